@@ -0,0 +1,155 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Heap usage tracking, enabled with the `count-allocations` feature.
+//!
+//! The feature installs [`CountingAllocator`] as the global allocator. It keeps its counters per
+//! thread, so parts that run concurrently (`cargo all --parallel`) do not skew each other.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Display,
+};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Wraps the system allocator and counts the allocations of the current thread.
+pub struct CountingAllocator;
+
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+fn record(bytes: isize, allocations: usize) {
+    // `try_with` fails while the thread is being torn down; those allocations are not tracked.
+    let _ = CURRENT.try_with(|current| {
+        let value = current.get() + bytes;
+        current.set(value);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(value)));
+        let _ = COUNT.try_with(|count| count.set(count.get() + allocations));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size() as isize, 1);
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size() as isize, 1);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record(-(layout.size() as isize), 0);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record(new_size as isize - layout.size() as isize, 1);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// The heap usage of a single call.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Memory {
+    /// The most bytes that were allocated at the same time, on top of what was allocated before.
+    pub peak_bytes: usize,
+    /// The number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak: {}, {} allocs",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Whether the counting allocator is installed, i.e. [`measure`] reports anything.
+pub const fn enabled() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+/// Calls `f` and reports its heap usage on the current thread, if [`enabled`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Memory>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let start = CURRENT.with(Cell::get);
+    PEAK.with(|peak| peak.set(start));
+    let count = COUNT.with(Cell::get);
+
+    let result = f();
+
+    let memory = Memory {
+        peak_bytes: (PEAK.with(Cell::get) - start).max(0) as usize,
+        allocations: COUNT.with(Cell::get) - count,
+    };
+
+    (result, Some(memory))
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.2} {unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (vec, memory) = measure(|| {
+            let scratch = vec![0_u8; 4096];
+            drop(scratch);
+            Vec::<u64>::with_capacity(16)
+        });
+
+        assert_eq!(vec.capacity(), 16);
+
+        match memory {
+            Some(memory) => {
+                assert_eq!(memory.allocations, 2);
+                assert_eq!(memory.peak_bytes, 4096);
+            }
+            None => assert!(!enabled()),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    file_path,
+    runner::{DayReport, Outcome},
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// How a solved part compares to the recorded answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// No answer has been recorded for this part yet.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✅"),
+            Verdict::Incorrect { expected } => write!(f, "❌ (expected {expected})"),
+            Verdict::Unknown => write!(f, "❔"),
+        }
+    }
+}
+
+/// The confirmed answers of a day, stored in `src/answers/YYYY/NN.txt` as `<part>: <answer>` lines.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    parts: BTreeMap<u8, String>,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        file_path("answers", year, day)
+    }
+
+    /// Loads the answers of `day`, or no answers if the file does not exist yet.
+    pub fn load(year: u16, day: u8) -> io::Result<Self> {
+        Self::read(&Self::path(year, day))
+    }
+
+    /// Reads answers from `path`, or no answers if the file does not exist.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = Self::path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let parts = contents
+            .lines()
+            .filter_map(|line| {
+                let (part, answer) = line.split_once(':')?;
+                Some((part.trim().parse().ok()?, answer.trim().to_string()))
+            })
+            .collect();
+
+        Self { parts }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.parts.get(&part).map(String::as_str)
+    }
+
+    /// Records `answer` unless `part` already has one. Returns whether it was recorded.
+    pub fn confirm(&mut self, part: u8, answer: &str) -> bool {
+        if self.parts.contains_key(&part) {
+            return false;
+        }
+
+        self.parts.insert(part, answer.to_string());
+        true
+    }
+
+    pub fn verify(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.parts
+            .iter()
+            .try_for_each(|(part, answer)| writeln!(f, "{part}: {answer}"))
+    }
+}
+
+/// Records the answers of all solved parts of `report` that have no answer yet.
+/// Returns the parts that were recorded.
+pub fn confirm(report: &DayReport) -> io::Result<Vec<u8>> {
+    let mut answers = Answers::load(report.year, report.day)?;
+
+    let confirmed: Vec<u8> = report
+        .parts
+        .iter()
+        .filter_map(|part| match &part.outcome {
+            Outcome::Solved { answer, .. } => {
+                answers.confirm(part.part, answer).then_some(part.part)
+            }
+            _ => None,
+        })
+        .collect();
+
+    if !confirmed.is_empty() {
+        answers.save(report.year, report.day)?;
+    }
+
+    Ok(confirmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("1: 514579\n");
+
+        assert_eq!(answers.verify(1, "514579"), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, "42"),
+            Verdict::Incorrect {
+                expected: "514579".into()
+            }
+        );
+        assert_eq!(answers.verify(2, "241861950"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_confirm() {
+        let mut answers = Answers::default();
+
+        assert!(answers.confirm(2, "241861950"));
+        assert!(!answers.confirm(2, "0"));
+        assert_eq!(answers.to_string(), "2: 241861950\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+}
@@ -0,0 +1,287 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{runner::DayReport, ANSI_BOLD, ANSI_RESET};
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub const DEFAULT_PATH: &str = "target/aoc-bench/baseline.json";
+pub const DEFAULT_THRESHOLD: f64 = 10_f64;
+
+pub struct BaselineConfig {
+    pub path: PathBuf,
+    /// Write the timings of this run to `path`.
+    pub save: bool,
+    /// Compare the timings of this run against `path`.
+    pub compare: bool,
+    /// How much slower than the baseline a part may get, in percent.
+    pub threshold: f64,
+}
+
+impl BaselineConfig {
+    /// Reads `--save-baseline`, `--compare-baseline`, `--baseline <path>` and
+    /// `--threshold <percent>` from the command line.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            save: args.contains("--save-baseline"),
+            compare: args.contains("--compare-baseline"),
+            path: args
+                .opt_value_from_str("--baseline")?
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_PATH)),
+            threshold: args
+                .opt_value_from_str("--threshold")?
+                .unwrap_or(DEFAULT_THRESHOLD),
+        })
+    }
+}
+
+/// Recorded timings, keyed by `(year, day, part)`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    timings: BTreeMap<(u16, u8, u8), Duration>,
+}
+
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent, positive when the part got slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, or an empty one if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_json())
+    }
+
+    /// Parses the format written by [`Baseline::to_json`]. Entries that lack a field are skipped.
+    pub fn parse(contents: &str) -> Self {
+        let timings = contents
+            .split('{')
+            .filter_map(|entry| {
+                let entry = entry.split('}').next()?;
+                let year = json_number(entry, "year")?;
+                let day = json_number(entry, "day")?;
+                let part = json_number(entry, "part")?;
+                let nanos = json_number(entry, "nanos")?;
+                Some((
+                    (year as u16, day as u8, part as u8),
+                    Duration::from_nanos(nanos),
+                ))
+            })
+            .collect();
+
+        Self { timings }
+    }
+
+    pub fn to_json(&self) -> String {
+        let entries: Vec<String> = self
+            .timings
+            .iter()
+            .map(|((year, day, part), elapsed)| {
+                format!(
+                    "  {{\"year\":{year},\"day\":{day},\"part\":{part},\"nanos\":{}}}",
+                    elapsed.as_nanos()
+                )
+            })
+            .collect();
+
+        format!("[\n{}\n]\n", entries.join(",\n"))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.timings.get(&(year, day, part)).copied()
+    }
+
+    /// Records the timing of every solved part, keeping entries for days that were not run.
+    pub fn update(&mut self, reports: &[DayReport]) {
+        for report in reports {
+            for part in &report.parts {
+                if let Some(elapsed) = part.outcome.elapsed() {
+                    self.timings
+                        .insert((report.year, report.day, part.part), elapsed);
+                }
+            }
+        }
+    }
+
+    /// Compares every solved part that has a recorded timing.
+    pub fn compare(&self, reports: &[DayReport], threshold: f64) -> Vec<Comparison> {
+        reports
+            .iter()
+            .flat_map(|report| {
+                report.parts.iter().filter_map(|part| {
+                    let current = part.outcome.elapsed()?;
+                    let baseline = self.get(report.year, report.day, part.part)?;
+                    let change = (current.as_secs_f64() / baseline.as_secs_f64() - 1_f64) * 100_f64;
+
+                    Some(Comparison {
+                        year: report.year,
+                        day: report.day,
+                        part: part.part,
+                        baseline,
+                        current,
+                        change,
+                        regressed: change > threshold,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%)",
+            self.year, self.day, self.part, self.baseline, self.current, self.change
+        )?;
+
+        if self.regressed {
+            write!(f, " {ANSI_BOLD}slower than baseline{ANSI_RESET}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Compares `reports` against the baseline and/or records them, depending on `config`.
+pub fn apply(config: &BaselineConfig, reports: &[DayReport]) -> io::Result<Vec<Comparison>> {
+    if !config.save && !config.compare {
+        return Ok(Vec::new());
+    }
+
+    let mut baseline = Baseline::load(&config.path)?;
+
+    let comparisons = if config.compare {
+        baseline.compare(reports, config.threshold)
+    } else {
+        Vec::new()
+    };
+
+    if config.save {
+        baseline.update(reports);
+        baseline.save(&config.path)?;
+    }
+
+    Ok(comparisons)
+}
+
+/// Applies `config` and prints the comparisons to stderr. Returns `false` if a part got
+/// slower than the threshold or the baseline file could not be accessed.
+pub fn report(config: &BaselineConfig, reports: &[DayReport]) -> bool {
+    match apply(config, reports) {
+        Ok(comparisons) => {
+            if config.compare {
+                eprintln!("---");
+                if comparisons.is_empty() {
+                    eprintln!("No baseline timings to compare against.");
+                }
+                comparisons
+                    .iter()
+                    .for_each(|comparison| eprintln!("{comparison}"));
+            }
+
+            if config.save {
+                eprintln!("Saved baseline to \"{}\".", config.path.display());
+            }
+
+            !comparisons.iter().any(|comparison| comparison.regressed)
+        }
+        Err(e) => {
+            eprintln!(
+                "Failed to access baseline \"{}\": {e}",
+                config.path.display()
+            );
+            false
+        }
+    }
+}
+
+fn json_number(entry: &str, key: &str) -> Option<u64> {
+    let (_, value) = entry.split_once(&format!("\"{key}\""))?;
+    let value = value.trim_start().strip_prefix(':')?.trim_start();
+    let end = value
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Outcome, PartReport};
+
+    fn report(day: u8, nanos: [Option<u64>; 2]) -> DayReport {
+        DayReport {
+            year: 2020,
+            day,
+            parts: [1, 2]
+                .into_iter()
+                .zip(nanos)
+                .map(|(part, nanos)| PartReport {
+                    part,
+                    outcome: match nanos {
+                        Some(nanos) => Outcome::Solved {
+                            answer: String::new(),
+                            elapsed: Duration::from_nanos(nanos),
+                            stats: None,
+                            memory: None,
+                        },
+                        None => Outcome::Unsolved,
+                    },
+                    verdict: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.update(&[
+            report(1, [Some(1500), None]),
+            report(15, [Some(7), Some(9)]),
+        ]);
+
+        assert_eq!(baseline.get(2020, 1, 1), Some(Duration::from_nanos(1500)));
+        assert_eq!(baseline.get(2020, 1, 2), None);
+        assert_eq!(baseline.get(2019, 1, 1), None);
+        assert_eq!(Baseline::parse(&baseline.to_json()), baseline);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.update(&[report(11, [Some(1000), Some(1000)])]);
+
+        let comparisons = baseline.compare(&[report(11, [Some(1050), Some(1200)])], 10_f64);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change - 20_f64).abs() < 1e-9);
+    }
+}
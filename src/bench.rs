@@ -0,0 +1,176 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Solver;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// Runs never stop before this many samples, even if the budget is exhausted.
+const MIN_SAMPLES: usize = 5;
+/// Runs never take more than this many samples, even if the budget allows it.
+const MAX_SAMPLES: usize = 10_000;
+
+pub struct BenchConfig {
+    /// How long to run the solver before samples are recorded.
+    pub warmup: Duration,
+    /// Take exactly this many samples instead of running for `budget`.
+    pub iterations: Option<usize>,
+    /// How long to keep sampling when `iterations` is not set.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: Duration::from_millis(100),
+            iterations: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Reads `--bench`, `--iterations <n>` and `--budget-ms <ms>` from the command line.
+    /// Returns `None` unless `--bench` is present.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let bench = args.contains("--bench");
+        let iterations = args.opt_value_from_str("--iterations")?;
+        let budget_ms: Option<u64> = args.opt_value_from_str("--budget-ms")?;
+
+        if !bench {
+            return Ok(None);
+        }
+
+        let default = Self::default();
+        Ok(Some(Self {
+            iterations,
+            budget: budget_ms.map_or(default.budget, Duration::from_millis),
+            ..default
+        }))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` for an empty sample set.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+
+        let count = samples.len();
+        let nanos: Vec<f64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect();
+
+        // for odd counts, both indices point at the same middle sample.
+        let median = (nanos[(count - 1) / 2] + nanos[count / 2]) / 2_f64;
+
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+
+        let variance = if count > 1 {
+            nanos
+                .iter()
+                .map(|sample| (sample - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Self {
+            samples: count,
+            min: samples[0],
+            median: Duration::from_nanos(median as u64),
+            mean: Duration::from_nanos(mean as u64),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"samples\":{},\"min\":{},\"median\":{},\"mean\":{},\"std_dev\":{}}}",
+            self.samples,
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.mean.as_nanos(),
+            self.std_dev.as_nanos()
+        )
+    }
+}
+
+/// Repeatedly times `solver` against `input` as described by `config`.
+pub fn measure(solver: Solver, input: &str, config: &BenchConfig) -> Stats {
+    let warmup = Instant::now();
+    while warmup.elapsed() < config.warmup {
+        let _ = black_box(solver(black_box(input)));
+    }
+
+    let mut samples = Vec::new();
+    let budget = Instant::now();
+
+    loop {
+        let timer = Instant::now();
+        let _ = black_box(solver(black_box(input)));
+        samples.push(timer.elapsed());
+
+        let done = match config.iterations {
+            Some(iterations) => samples.len() >= iterations,
+            None => {
+                samples.len() >= MAX_SAMPLES
+                    || (samples.len() >= MIN_SAMPLES && budget.elapsed() >= config.budget)
+            }
+        };
+
+        if done {
+            break;
+        }
+    }
+
+    Stats::from_samples(&mut samples).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        // sample standard deviation of 1, 2, 3, 4 is sqrt(5 / 3).
+        assert_eq!(stats.std_dev, Duration::from_nanos(1290));
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_measure_iterations() {
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            iterations: Some(3),
+            ..BenchConfig::default()
+        };
+
+        assert_eq!(measure(|_| Ok(None), "", &config).samples, 3);
+    }
+}
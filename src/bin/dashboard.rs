@@ -0,0 +1,381 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    answers::Answers,
+    aoc_cli,
+    bench::BenchConfig,
+    dashboard::{self, DayStatus, LastRun},
+    days, examples,
+    input::{self, InputSource},
+    puzzle, runner,
+    submit::Ledger,
+    ANSI_BOLD, ANSI_RESET,
+};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::{
+    io::{self, Write},
+    process,
+    time::Duration,
+};
+
+/// Parts that take longer than this are abandoned, so that the dashboard stays responsive.
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The lines above and below the list of days.
+const CHROME_LINES: u16 = 6;
+
+struct Args {
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::default_year),
+    })
+}
+
+#[derive(Clone, Copy)]
+enum Action {
+    Run,
+    Stats,
+    Puzzle,
+    Download,
+}
+
+struct Dashboard {
+    years: Vec<u16>,
+    year: u16,
+    days: Vec<DayStatus>,
+    selected: usize,
+    message: String,
+}
+
+impl Dashboard {
+    fn new(year: u16) -> Self {
+        let mut years: Vec<u16> = days::YEARS
+            .iter()
+            .map(|registered| registered.year)
+            .collect();
+        years.push(year);
+        years.sort();
+        years.dedup();
+
+        Self {
+            years,
+            year,
+            days: DayStatus::all(year),
+            selected: 0,
+            message: String::new(),
+        }
+    }
+
+    fn day(&self) -> &DayStatus {
+        &self.days[self.selected]
+    }
+
+    fn switch_year(&mut self, offset: isize) {
+        let index = self
+            .years
+            .iter()
+            .position(|&year| year == self.year)
+            .unwrap_or(0);
+        let index = index
+            .saturating_add_signed(offset)
+            .min(self.years.len() - 1);
+        self.year = self.years[index];
+        self.days = DayStatus::all(self.year);
+        self.message.clear();
+    }
+
+    fn reload_day(&mut self) {
+        let day = self.day().day;
+        self.days[self.selected] = DayStatus::load(self.year, day);
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (_, height) = terminal::size()?;
+        let visible = usize::from(height.saturating_sub(CHROME_LINES)).max(1);
+        let top = (self.selected + 1).saturating_sub(visible);
+
+        queue!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+        queue!(
+            out,
+            SetAttribute(Attribute::Bold),
+            Print(format!("🎄 Advent of Code {} 🎄", self.year)),
+            SetAttribute(Attribute::Reset),
+            MoveTo(0, 2),
+            SetAttribute(Attribute::Bold),
+            Print(format!(
+                "{:>3}  {:<30} {:^6} {:^6} {:^8} {:^6} {:^3} {:^3} {:^8} {:>10}",
+                "Day",
+                "Title",
+                "Module",
+                "Input",
+                "Examples",
+                "Puzzle",
+                "★1",
+                "★2",
+                "Verified",
+                "Last run"
+            )),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        for (row, status) in self.days.iter().enumerate().skip(top).take(visible) {
+            let y = 3 + (row - top) as u16;
+            queue!(out, MoveTo(0, y))?;
+            if row == self.selected {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            queue!(out, Print(row_text(status)), SetAttribute(Attribute::Reset))?;
+        }
+
+        let footer = 3 + visible.min(self.days.len()) as u16 + 1;
+        queue!(
+            out,
+            MoveTo(0, footer),
+            Print(&self.message),
+            MoveTo(0, footer + 1),
+            SetAttribute(Attribute::Dim),
+            Print("↑/↓ select  ←/→ year  enter run  s stats  p puzzle  d download  q quit"),
+            SetAttribute(Attribute::Reset),
+        )?;
+
+        out.flush()
+    }
+}
+
+fn mark(present: bool) -> &'static str {
+    if present {
+        "✓"
+    } else {
+        "·"
+    }
+}
+
+fn row_text(status: &DayStatus) -> String {
+    let title: String = status.title.unwrap_or_default().chars().take(30).collect();
+    let examples = match status.examples {
+        0 => String::from("·"),
+        count => count.to_string(),
+    };
+    let verified = match status.last_run.as_ref().and_then(LastRun::verified) {
+        Some(true) => "✓",
+        Some(false) => "✗",
+        None => "·",
+    };
+    let last_run = status
+        .last_run
+        .as_ref()
+        .and_then(LastRun::elapsed)
+        .map(|elapsed| format!("{elapsed:.2?}"))
+        .unwrap_or_default();
+
+    format!(
+        "{:>3}  {:<30} {:^6} {:^6} {:^8} {:^6} {:^3} {:^3} {:^8} {:>10}",
+        status.day,
+        title,
+        mark(status.scaffolded),
+        mark(status.input),
+        examples,
+        mark(status.puzzle),
+        mark(status.answers.get(1).is_some()),
+        mark(status.answers.get(2).is_some()),
+        verified,
+        last_run
+    )
+}
+
+/// Holds the terminal in raw mode on the alternate screen, and restores it when dropped.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self)
+    }
+
+    /// Runs `f` on the normal screen, e.g. to print the output of a day, and returns once Enter
+    /// is pressed.
+    fn suspend(&mut self, f: impl FnOnce()) -> io::Result<()> {
+        execute!(io::stdout(), Show, LeaveAlternateScreen)?;
+        terminal::disable_raw_mode()?;
+
+        f();
+
+        println!("\nPress Enter to return to the dashboard.");
+        io::stdin().read_line(&mut String::new())?;
+
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo dashboard --year 2020`");
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = run(Dashboard::new(args.year)) {
+        eprintln!("Failed to draw the dashboard: {e}");
+        process::exit(1);
+    }
+}
+
+fn run(mut dashboard: Dashboard) -> io::Result<()> {
+    let mut screen = Screen::enter()?;
+    let mut out = io::stdout();
+
+    loop {
+        dashboard.draw(&mut out)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let action = match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Up | KeyCode::Char('k') => {
+                dashboard.selected = dashboard.selected.saturating_sub(1);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                dashboard.selected = (dashboard.selected + 1).min(dashboard.days.len() - 1);
+                None
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                dashboard.switch_year(-1);
+                None
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                dashboard.switch_year(1);
+                None
+            }
+            KeyCode::Enter | KeyCode::Char('r') => Some(Action::Run),
+            KeyCode::Char('s') => Some(Action::Stats),
+            KeyCode::Char('p') => Some(Action::Puzzle),
+            KeyCode::Char('d') => Some(Action::Download),
+            _ => None,
+        };
+
+        if let Some(action) = action {
+            perform(&mut dashboard, &mut screen, action)?;
+        }
+    }
+}
+
+fn perform(dashboard: &mut Dashboard, screen: &mut Screen, action: Action) -> io::Result<()> {
+    let (year, day) = (dashboard.year, dashboard.day().day);
+    dashboard.message.clear();
+
+    match action {
+        Action::Run | Action::Stats if days::get(year, day).is_none() => {
+            dashboard.message = if dashboard.day().scaffolded {
+                format!("Day {day} was scaffolded after the dashboard was built, restart it to run the day.")
+            } else {
+                format!("Day {day} is not scaffolded yet. Run `cargo scaffold {day} --year {year}` first.")
+            };
+            return Ok(());
+        }
+        Action::Puzzle if !dashboard.day().puzzle => {
+            dashboard.message =
+                format!("The puzzle of day {day} is not downloaded yet, press d to download it.");
+            return Ok(());
+        }
+        Action::Run => screen.suspend(|| run_day(year, day, None))?,
+        Action::Stats => screen.suspend(|| {
+            run_day(year, day, Some(BenchConfig::default()));
+            print_stats(year, day);
+        })?,
+        Action::Puzzle => {
+            screen.suspend(
+                || match std::fs::read_to_string(dashboard::puzzle_path(year, day)) {
+                    Ok(markdown) => print!("{}", puzzle::render(&markdown)),
+                    Err(e) => eprintln!("Failed to read puzzle description: {e}"),
+                },
+            )?
+        }
+        Action::Download => screen.suspend(|| {
+            if let Err(e) = aoc_cli::download(day, year, false) {
+                eprintln!("Failed to download day {day} of {year}: {e}");
+            }
+        })?,
+    }
+
+    dashboard.reload_day();
+    Ok(())
+}
+
+fn run_day(year: u16, day: u8, bench: Option<BenchConfig>) {
+    println!("{ANSI_BOLD}Day {day:02} of {year}{ANSI_RESET}\n");
+
+    let options = runner::Options {
+        bench,
+        timeout: Some(TIMEOUT),
+        ..runner::Options::default()
+    };
+    let report = runner::run_day(year, day, &options);
+    report.print();
+
+    if let Err(e) = LastRun::save(&report) {
+        eprintln!("Failed to record the run: {e}");
+    }
+}
+
+fn print_stats(year: u16, day: u8) {
+    println!("\n{ANSI_BOLD}Files{ANSI_RESET}");
+
+    match input::load(year, day, &InputSource::default()) {
+        Ok(input) => println!(
+            "Input: {} lines, {} bytes",
+            input.lines().count(),
+            input.len()
+        ),
+        Err(e) => println!("Input: {e}"),
+    }
+
+    let labels: Vec<String> = examples::find(year, day)
+        .unwrap_or_default()
+        .iter()
+        .map(|example| example.label())
+        .collect();
+    println!("Examples: {}", labels.join(", "));
+
+    let answers = Answers::load(year, day).unwrap_or_default();
+    println!("\n{ANSI_BOLD}Recorded answers{ANSI_RESET}");
+    print!("{answers}");
+
+    let ledger = Ledger::load(year, day).unwrap_or_default();
+    let wrong = ledger.to_string();
+    if !wrong.is_empty() {
+        println!("\n{ANSI_BOLD}Wrong answers{ANSI_RESET}");
+        print!("{wrong}");
+    }
+}
@@ -2,19 +2,23 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::{aoc_cli, client, days};
 use std::process;
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
+    refresh: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        refresh: args.contains("--refresh"),
+        year: args
+            .opt_value_from_fn(["-y", "--year"], client::parse_year)?
+            .unwrap_or_else(days::default_year),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
@@ -27,20 +31,7 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            process::exit(1);
-        }
+    if let Err(e) = aoc_cli::read(args.day, args.year, args.refresh) {
+        e.exit(&format!("failed to read day {} of {}", args.day, args.year));
     }
 }
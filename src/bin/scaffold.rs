@@ -2,113 +2,445 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{answers::Answers, aoc_cli, client, days, puzzle, template};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs, io,
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<usize> {
-    None
+const YEAR_REGISTRY_TEMPLATE: &str = r###"/*
+ * The solutions of {{year}}. Every solution lives in its own `dayNN` module and is listed in `DAYS` below.
+ * `INPUTS` embeds the matching input files when the `embed-inputs` feature is enabled.
+ * `cargo scaffold` adds new days to all three lists automatically.
+ */
+use crate::Day;
+
+#[rustfmt::skip]
+pub const DAYS: &[Day] = &[
+];
+
+/// Inputs compiled into the binaries with the `embed-inputs` feature.
+#[cfg(feature = "embed-inputs")]
+#[rustfmt::skip]
+pub const INPUTS: &[(u8, &str)] = &[
+];
+"###;
+
+const REGISTRY_PATH: &str = "src/days/mod.rs";
+
+struct Args {
+    year: u16,
+    day: u8,
+    template: String,
+    dry_run: bool,
+    force: bool,
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        dry_run: args.contains("--dry-run"),
+        force: args.contains("--force"),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| template::DEFAULT_NAME.into()),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::default_year),
+        day: args.free_from_str()?,
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+/// The first number in `line`, e.g. `7` for `    Day::new::<day07::Day07>(),`.
+fn entry_number(line: &str) -> Option<u16> {
+    let start = line.find(|char: char| char.is_ascii_digit())?;
+    let digits: String = line[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// Inserts `entry` into the run of lines starting with `prefix`, keeping it ordered by `number`
+/// (the day or year of the entry), unless it is already there. Falls back to inserting right after
+/// the `anchor` line if the list is still empty.
+fn insert_entry(lines: &mut Vec<String>, prefix: &str, anchor: &str, entry: String, number: u16) {
+    if lines.contains(&entry) {
+        return;
+    }
+
+    let entries: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with(prefix))
+        .map(|(index, _)| index)
+        .collect();
+
+    let index = entries
+        .iter()
+        .find(|&&index| entry_number(&lines[index]).is_some_and(|other| other > number))
+        .copied()
+        .or_else(|| entries.last().map(|index| index + 1))
+        .or_else(|| {
+            lines
+                .iter()
+                .position(|line| line.starts_with(anchor))
+                .map(|index| index + 1)
+        })
+        .unwrap_or(lines.len());
+
+    lines.insert(index, entry);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Adds the module, its `DAYS` entry and its `INPUTS` entry to the registry of a year.
+fn register_day(registry: &str, year: u16, day: u8) -> String {
+    let day_padded = format!("{day:02}");
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    insert_entry(
+        &mut lines,
+        "pub mod day",
+        "use crate::Day;",
+        format!("pub mod day{day_padded};"),
+        day.into(),
+    );
+    insert_entry(
+        &mut lines,
+        "    Day::new::<",
+        "pub const DAYS",
+        format!("    Day::new::<day{day_padded}::Day{day_padded}>(),"),
+        day.into(),
+    );
+    insert_entry(
+        &mut lines,
+        "    (",
+        "pub const INPUTS",
+        format!("    ({day}, include_str!(\"../../inputs/{year}/{day_padded}.txt\")),"),
+        day.into(),
+    );
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    lines.join("\n") + "\n"
+}
+
+/// Adds the module of a year, its `YEARS` entry and its `INPUTS` entry to the root registry.
+fn register_year(registry: &str, year: u16) -> String {
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    insert_entry(
+        &mut lines,
+        "pub mod y",
+        "use crate::Day;",
+        format!("pub mod y{year};"),
+        year,
+    );
+    insert_entry(
+        &mut lines,
+        "    Year::new(",
+        "pub static YEARS",
+        format!("    Year::new({year}, y{year}::DAYS),"),
+        year,
+    );
+    insert_entry(
+        &mut lines,
+        "    (",
+        "pub static INPUTS",
+        format!("    ({year}, y{year}::INPUTS),"),
+        year,
+    );
+
+    lines.join("\n") + "\n"
+}
+
+/// Downloads the puzzle if a session cookie is configured and returns its description, which may
+/// also have been downloaded before.
+fn puzzle_description(year: u16, day: u8, puzzle_path: &str) -> Option<String> {
+    if client::session().is_some() && client::time_until_unlock(year, day).is_none() {
+        if let Err(e) = aoc_cli::download(day, year, false) {
+            eprintln!("Failed to download day {day} of {year}, continuing without it: {e}");
+        }
+        println!("---");
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    fs::read_to_string(puzzle_path).ok()
+}
+
+/// The expected value of a part in the generated tests, e.g. `Some(514579)`. An example answer
+/// that does not fit the `usize` of the templates, e.g. a code of letters, becomes a `todo!` that
+/// names it, so that its test fails until the assertion is adapted.
+fn expected_answer(answer: Option<&str>) -> String {
+    match answer {
+        // `parse` also accepts a leading `+`, which is not a valid literal.
+        Some(answer) if answer.parse::<usize>().is_ok() && !answer.starts_with('+') => {
+            format!("Some({answer})")
+        }
+        Some(answer) => format!("Some(todo!(\"assert the example answer {{}}\", {answer:?}))"),
+        None => "None".into(),
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+#[derive(Clone, Copy, PartialEq)]
+enum Action {
+    Create,
+    Update,
+    Overwrite,
+}
+
+/// A file that scaffolding writes.
+struct Change {
+    action: Action,
+    /// What the file is, e.g. `module file`.
+    description: String,
+    path: PathBuf,
+    contents: String,
+}
+
+impl Change {
+    fn new(description: impl Into<String>, path: impl Into<PathBuf>, contents: String) -> Self {
+        let path = path.into();
+        Self {
+            action: if path.exists() {
+                Action::Overwrite
+            } else {
+                Action::Create
+            },
+            description: description.into(),
+            path,
+            contents,
+        }
+    }
+
+    /// Like [`Change::new`], but for registries that are updated in place.
+    fn registry(path: impl Into<PathBuf>, contents: String) -> Self {
+        Self {
+            action: Action::Update,
+            ..Self::new("solution registry", path, contents)
+        }
+    }
+
+    fn describe(&self, dry_run: bool) -> String {
+        let verb = match (self.action, dry_run) {
+            (Action::Create, false) => "Created",
+            (Action::Update, false) => "Updated",
+            (Action::Overwrite, false) => "Overwrote",
+            (Action::Create, true) => "Would create",
+            (Action::Update, true) => "Would update",
+            (Action::Overwrite, true) => "Would overwrite",
+        };
+
+        format!("{verb} {} \"{}\"", self.description, self.path.display())
+    }
+}
+
+/// Writes all `changes` or, if one of them fails, restores the previous state of every file and
+/// directory that was touched.
+fn apply(changes: &[Change]) -> io::Result<()> {
+    // the previous contents of every written file, `None` if it was created.
+    let mut written: Vec<(&Path, Option<Vec<u8>>)> = vec![];
+    let mut created_dirs: Vec<PathBuf> = vec![];
+
+    let result = changes.iter().try_for_each(|change| {
+        for dir in missing_dirs(&change.path) {
+            fs::create_dir(&dir)?;
+            created_dirs.push(dir);
+        }
+
+        let previous = fs::read(&change.path).ok();
+        fs::write(&change.path, &change.contents)?;
+        written.push((&change.path, previous));
+        Ok(())
+    });
+
+    if result.is_err() {
+        // best effort, the error that caused the rollback is the one worth reporting.
+        for (path, previous) in written.into_iter().rev() {
+            let _ = match previous {
+                Some(contents) => fs::write(path, contents),
+                None => fs::remove_file(path),
+            };
+        }
+        for dir in created_dirs.into_iter().rev() {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+
+    result
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// The ancestors of `path` that do not exist yet, outermost first.
+fn missing_dirs(path: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = path
+        .ancestors()
+        .skip(1)
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .map(Path::to_path_buf)
+        .collect();
+    dirs.reverse();
+    dirs
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn read_registry(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("Failed to read solution registry \"{path}\": {e}");
+        process::exit(1);
+    })
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        year,
+        day,
+        template,
+        dry_run,
+        force,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7 --year 2020`"
+            );
             process::exit(1);
         }
     };
 
+    if !(1..=25).contains(&day) {
+        eprintln!("Day {day} does not exist, Advent of Code has days 1 to 25.");
+        process::exit(1);
+    }
+
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let input_path = format!("src/inputs/{year}/{day_padded}.txt");
+    let example_path = format!("src/examples/{year}/{day_padded}.txt");
+    let module_path = format!("src/days/y{year}/day{day_padded}.rs");
+    let year_registry_path = format!("src/days/y{year}/mod.rs");
+    let puzzle_path = format!("src/puzzles/{year}/{day_padded}.md");
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
+    let template = template::load(&template).unwrap_or_else(|e| {
+        eprintln!("Failed to load module template: {e}");
+        process::exit(1);
+    });
+
+    if Path::new(&module_path).exists() && !force {
+        eprintln!(
+            "Failed to create module file: \"{module_path}\" already exists. Append `--force` to overwrite it."
+        );
+        process::exit(1);
+    }
+
+    // downloading is not part of the scaffold itself, it only fills the puzzle and input cache.
+    let description = if dry_run {
+        fs::read_to_string(&puzzle_path).ok()
+    } else {
+        puzzle_description(year, day, &puzzle_path)
     };
+    let description = description.as_deref();
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let title = description.and_then(puzzle::title);
+    let example = description.and_then(puzzle::example);
+    let mut example_answers = Answers::default();
+    for part in [1, 2] {
+        if let Some(answer) =
+            description.and_then(|markdown| puzzle::example_answer(markdown, part))
+        {
+            example_answers.confirm(part, &answer);
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    }
+
+    let module = template::render(
+        &template,
+        &[
+            ("day", &day.to_string()),
+            ("day_padded", &day_padded),
+            ("year", &year.to_string()),
+            ("title", &title.unwrap_or_default().replace('"', "\\\"")),
+            (
+                "part_one_expected",
+                &expected_answer(example_answers.get(1)),
+            ),
+            (
+                "part_two_expected",
+                &expected_answer(example_answers.get(2)),
+            ),
+        ],
+    )
+    .unwrap_or_else(|e| {
+        eprintln!("Failed to render module template: {e}");
+        process::exit(1);
+    });
+    // placeholders that are empty, e.g. an unknown title, must not leave trailing whitespace.
+    let module: String = module
+        .lines()
+        .map(|line| line.trim_end().to_string() + "\n")
+        .collect();
+
+    let mut changes = vec![Change::new("module file", &module_path, module)];
+
+    if Path::new(&year_registry_path).exists() {
+        let registry = read_registry(&year_registry_path);
+        let updated = register_day(&registry, year, day);
+        if updated != registry {
+            changes.push(Change::registry(&year_registry_path, updated));
         }
+    } else {
+        let registry =
+            template::render(YEAR_REGISTRY_TEMPLATE, &[("year", &year.to_string())]).unwrap();
+        changes.push(Change::new(
+            "solution registry",
+            &year_registry_path,
+            register_day(&registry, year, day),
+        ));
+        changes.push(Change::registry(
+            REGISTRY_PATH,
+            register_year(&read_registry(REGISTRY_PATH), year),
+        ));
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    // inputs are never overwritten, they are the one thing that can not be regenerated offline.
+    if !Path::new(&input_path).exists() {
+        changes.push(Change::new("empty input file", &input_path, String::new()));
+    }
+
+    let example_is_empty = fs::metadata(&example_path).map_or(true, |file| file.len() == 0);
+    match example {
+        Some(example) if force || example_is_empty => {
+            changes.push(Change::new("example file", &example_path, example));
+
+            let answers_path = Path::new(&example_path).with_extension("answers");
+            let answers = example_answers.to_string();
+            if !answers.is_empty() && (force || !answers_path.exists()) {
+                changes.push(Change::new("example answers file", answers_path, answers));
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+        None if !Path::new(&example_path).exists() => {
+            changes.push(Change::new(
+                "empty example file",
+                &example_path,
+                String::new(),
+            ));
         }
+        _ => {}
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    if dry_run {
+        for change in &changes {
+            println!("{}", change.describe(true));
         }
+        return;
+    }
+
+    if let Err(e) = apply(&changes) {
+        eprintln!("Failed to scaffold day {day} of {year}, no files were changed: {e}");
+        process::exit(1);
+    }
+
+    for change in &changes {
+        println!("{}", change.describe(false));
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
+        "🎄 Type `cargo solve {} --year {year}` to run your solution.",
         &day_padded
     );
 }
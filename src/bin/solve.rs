@@ -0,0 +1,101 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    answers::{self, Answers},
+    baseline::{self, BaselineConfig},
+    bench::BenchConfig,
+    days,
+    input::InputSource,
+    runner,
+};
+use std::{process, time::Duration};
+
+struct Args {
+    year: u16,
+    day: u8,
+    json: bool,
+    check: bool,
+    confirm: bool,
+    bench: Option<BenchConfig>,
+    baseline: BaselineConfig,
+    timeout: Option<Duration>,
+    input: InputSource,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        json: args.contains("--json"),
+        check: args.contains("--check"),
+        confirm: args.contains("--confirm"),
+        bench: BenchConfig::from_args(&mut args)?,
+        baseline: BaselineConfig::from_args(&mut args)?,
+        timeout: runner::Options::timeout_from_args(&mut args)?,
+        input: InputSource::from_args(&mut args)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::default_year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo solve 7`");
+            process::exit(1);
+        }
+    };
+
+    let (year, day) = (args.year, args.day);
+    if days::get(year, day).is_none() {
+        eprintln!(
+            "Day {day} of {year} is not registered. Try running `cargo scaffold {day} --year {year}` first."
+        );
+        process::exit(1);
+    }
+
+    let options = runner::Options {
+        bench: args.bench,
+        input: args.input,
+        timeout: args.timeout,
+    };
+
+    let report = runner::run_day(year, day, &options);
+
+    if args.json {
+        report.json_lines().for_each(|line| println!("{line}"));
+    } else {
+        report.print();
+    }
+
+    if args.confirm {
+        confirm_answers(&report);
+    }
+
+    let mismatch = args.check && report.has_mismatch();
+
+    if !baseline::report(&args.baseline, &[report]) || mismatch {
+        process::exit(1);
+    }
+}
+
+fn confirm_answers(report: &runner::DayReport) {
+    match answers::confirm(report) {
+        Ok(parts) => {
+            for part in parts {
+                eprintln!(
+                    "Recorded answer for part {part} in \"{}\".",
+                    Answers::path(report.year, report.day).display()
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to record answers: {e}");
+            process::exit(1);
+        }
+    }
+}
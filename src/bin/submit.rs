@@ -0,0 +1,117 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    answers::Answers,
+    aoc_cli, client, days,
+    input::{self, InputSource},
+    runner::{self, Outcome},
+    submit::{Ledger, Response},
+};
+use std::{process, time::Duration};
+
+struct Args {
+    year: u16,
+    day: u8,
+    part: u8,
+    timeout: Option<Duration>,
+    input: InputSource,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        timeout: runner::Options::timeout_from_args(&mut args)?,
+        input: InputSource::from_args(&mut args)?,
+        year: args
+            .opt_value_from_fn(["-y", "--year"], client::parse_year)?
+            .unwrap_or_else(days::default_year),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let (year, day, part) = (args.year, args.day, args.part);
+    let Some(registered) = days::get(year, day) else {
+        eprintln!(
+            "Day {day} of {year} is not registered. Try running `cargo scaffold {day} --year {year}` first."
+        );
+        process::exit(1);
+    };
+
+    let Some((_, solver)) = registered.parts().into_iter().find(|(p, _)| *p == part) else {
+        eprintln!("Part {part} does not exist, expected 1 or 2.");
+        process::exit(1);
+    };
+
+    let input = input::load(year, day, &args.input).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    let options = runner::Options {
+        timeout: args.timeout,
+        ..runner::Options::default()
+    };
+
+    let answer = match runner::run_part(solver, &input, &options) {
+        Outcome::Solved { answer, .. } => answer,
+        outcome => {
+            eprintln!("Part {part} has no answer to submit: {outcome}");
+            process::exit(1);
+        }
+    };
+
+    let mut ledger = Ledger::load(year, day).unwrap_or_else(|e| {
+        eprintln!("Failed to read submitted answers: {e}");
+        process::exit(1);
+    });
+
+    if let Err(rejection) = ledger.check(part, &answer) {
+        eprintln!("Not submitting {answer} for part {part}: {rejection}");
+        process::exit(1);
+    }
+
+    println!("Submitting {answer} for day {day} of {year}, part {part}...");
+
+    let response = aoc_cli::submit(day, year, part, &answer)
+        .unwrap_or_else(|e| e.exit("failed to submit answer"));
+
+    println!("{response}");
+
+    match response {
+        Response::Correct => record_answer(year, day, part, &answer),
+        Response::Incorrect { hint, .. } => {
+            ledger.record(part, &answer, hint);
+            if let Err(e) = ledger.save(year, day) {
+                eprintln!("Failed to record wrong answer: {e}");
+            }
+            process::exit(1);
+        }
+        _ => process::exit(1),
+    }
+}
+
+fn record_answer(year: u16, day: u8, part: u8, answer: &str) {
+    let mut answers = Answers::load(year, day).unwrap_or_default();
+
+    if answers.confirm(part, answer) {
+        match answers.save(year, day) {
+            Ok(()) => eprintln!(
+                "Recorded answer for part {part} in \"{}\".",
+                Answers::path(year, day).display()
+            ),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
+}
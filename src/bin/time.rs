@@ -0,0 +1,69 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{bench::BenchConfig, days, readme, runner};
+use std::{path::Path, process, time::Duration};
+
+struct Args {
+    year: u16,
+    bench: BenchConfig,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let default = BenchConfig::default();
+    let budget_ms: Option<u64> = args.opt_value_from_str("--budget-ms")?;
+
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::default_year),
+        bench: BenchConfig {
+            iterations: args.opt_value_from_str("--iterations")?,
+            budget: budget_ms.map_or(default.budget, Duration::from_millis),
+            ..default
+        },
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    if cfg!(debug_assertions) {
+        eprintln!("Timings are measured in a debug build. Run `cargo time` for release timings.");
+    }
+
+    let options = runner::Options {
+        bench: Some(args.bench),
+        ..runner::Options::default()
+    };
+
+    let Some(year) = days::year(args.year) else {
+        eprintln!("No days of {} are registered.", args.year);
+        process::exit(1);
+    };
+
+    let reports: Vec<_> = year
+        .days
+        .iter()
+        .map(|solution| {
+            println!("Timing day {:02} of {}...", solution.day, solution.year);
+            runner::run_day(solution.year, solution.day, &options)
+        })
+        .collect();
+
+    match readme::update(Path::new("README.md"), &reports) {
+        Ok(_) => println!("🎄 Updated the benchmarking table in \"README.md\"."),
+        Err(e) => {
+            eprintln!("Failed to update README: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    days,
+    watch::{self, PartRun, Run, Snapshot, TestSummary},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process, thread,
+    time::Duration,
+};
+
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The number of compiler output lines shown when the build fails.
+const MAX_ERROR_LINES: usize = 30;
+
+struct Args {
+    year: u16,
+    day: u8,
+    timeout: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let timeout_ms: Option<u64> = args.opt_value_from_str("--timeout-ms")?;
+
+    Ok(Args {
+        timeout: Duration::from_millis(timeout_ms.unwrap_or(10_000)),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(days::default_year),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let Args { year, day, timeout } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}. example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    if !watch::module_path(year, day).exists() {
+        eprintln!(
+            "Day {day} of {year} does not exist. Try running `cargo scaffold {day} --year {year}` first."
+        );
+        process::exit(1);
+    }
+
+    // the title of a day scaffolded after the watcher was built is shown from the next start on.
+    let heading = match days::get(year, day) {
+        Some(registered) if !registered.title.is_empty() => {
+            format!("Day {day:02} of {year}: {}", registered.title)
+        }
+        _ => format!("Day {day:02} of {year}"),
+    };
+
+    let mut snapshot = Snapshot::take(year, day);
+    let mut trigger = String::from("start");
+
+    loop {
+        println!("{ANSI_CLEAR}🎄 {ANSI_BOLD}{heading}{ANSI_RESET} 🎄\n");
+        println!("Running ({trigger})...");
+
+        let run = watch::run(year, day, timeout).unwrap_or_else(|e| {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        });
+
+        print!("{ANSI_CLEAR}");
+        println!("🎄 {ANSI_BOLD}{heading}{ANSI_RESET} 🎄\n");
+        print_run(&run, &trigger);
+        println!(
+            "\n{ANSI_ITALIC}Watching {} files, press Ctrl-C to quit.{ANSI_RESET}",
+            snapshot.paths().count()
+        );
+
+        let (next, changes) = wait_for_changes(year, day, &snapshot);
+        trigger = match changes.as_slice() {
+            [] => String::from("files removed"),
+            [path] => format!("changed: {}", relative(path)),
+            [path, rest @ ..] => format!("changed: {} and {} more", relative(path), rest.len()),
+        };
+        snapshot = next;
+    }
+}
+
+/// Polls the files of the day until they change and stop changing, which editors that write in
+/// several steps rely on.
+fn wait_for_changes(year: u16, day: u8, previous: &Snapshot) -> (Snapshot, Vec<PathBuf>) {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut current = Snapshot::take(year, day);
+        if current == *previous {
+            continue;
+        }
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let settled = Snapshot::take(year, day);
+            if settled == current {
+                break;
+            }
+            current = settled;
+        }
+
+        let changes = current.changes(previous).into_iter().cloned().collect();
+        return (current, changes);
+    }
+}
+
+fn relative(path: &Path) -> String {
+    let cwd = env::current_dir().unwrap();
+    path.strip_prefix(&cwd)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+fn print_run(run: &Run, trigger: &str) {
+    match run {
+        Run::BuildFailed(output) => {
+            println!("Build failed ({trigger}):\n");
+            for line in output.lines().take(MAX_ERROR_LINES) {
+                println!("{line}");
+            }
+            if output.lines().count() > MAX_ERROR_LINES {
+                println!("...");
+            }
+        }
+        Run::Finished {
+            tests,
+            parts,
+            error,
+            elapsed,
+        } => {
+            println!("{ANSI_ITALIC}Ran in {elapsed:.2?} ({trigger}).{ANSI_RESET}\n");
+            print_tests(tests.as_ref());
+            for part in parts {
+                print_part(part);
+            }
+            if let Some(error) = error {
+                println!("\n{}", error.trim_end());
+            }
+        }
+    }
+}
+
+fn print_tests(tests: Option<&TestSummary>) {
+    match tests {
+        None => println!("{ANSI_BOLD}Tests {ANSI_RESET}  ❌ did not run, check `cargo test`"),
+        Some(TestSummary {
+            passed, failed: 0, ..
+        }) => {
+            println!("{ANSI_BOLD}Tests {ANSI_RESET}  ✅ {passed} passed")
+        }
+        Some(TestSummary {
+            passed,
+            failed,
+            failures,
+        }) => {
+            println!("{ANSI_BOLD}Tests {ANSI_RESET}  ❌ {failed} failed, {passed} passed");
+            for name in failures {
+                // e.g. `days::y2020::day01::tests::test_part_two` or `y2020::day01::example::part_two`.
+                let name = name.strip_prefix("days::").unwrap_or(name);
+                let name = name.splitn(3, "::").last().unwrap_or(name);
+                let name = name.strip_prefix("tests::").unwrap_or(name);
+                println!("          - {name}");
+            }
+        }
+    }
+}
+
+fn print_part(part: &PartRun) {
+    let label = format!("{ANSI_BOLD}Part {}{ANSI_RESET}", part.part);
+
+    let Some(answer) = &part.answer else {
+        let detail = match &part.message {
+            Some(message) => message.clone(),
+            None => part.status.replace('_', " "),
+        };
+        println!("{label}  ➖ {detail}");
+        return;
+    };
+
+    let timing = part
+        .elapsed
+        .map(|elapsed| format!("{elapsed:.2?}"))
+        .unwrap_or_default();
+    let verdict = match (part.verdict.as_deref(), &part.expected) {
+        (Some("correct"), _) => String::from("✅"),
+        (Some("incorrect"), Some(expected)) => format!("❌ (expected {expected})"),
+        _ => String::from("❔"),
+    };
+
+    println!("{label}  {answer:<20} {ANSI_ITALIC}{timing:>10}{ANSI_RESET}  {verdict}");
+}
@@ -0,0 +1,423 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! A minimal client for the Advent of Code website, used by `cargo download`, `cargo read`
+//! and `cargo submit`.
+//!
+//! The session cookie is read from the `ADVENT_OF_CODE_SESSION` environment variable or from
+//! `~/.adventofcode.session`, the same places `aoc-cli` looks. Requests go to
+//! [`DEFAULT_BASE_URL`] unless `AOC_BASE_URL` points somewhere else, e.g. a local mock server.
+//!
+//! To go easy on the website, consecutive requests are at least [`MIN_INTERVAL`] apart, also across
+//! runs: the time of the last request is kept in [`STATE_PATH`].
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const STATE_PATH: &str = "target/aoc-client/last-request";
+pub const MIN_INTERVAL: Duration = Duration::from_secs(3);
+/// The year of the first Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+const SESSION_VAR: &str = "ADVENT_OF_CODE_SESSION";
+const BASE_URL_VAR: &str = "AOC_BASE_URL";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The site answered with a status other than 200.
+    Status {
+        code: u16,
+        body: String,
+    },
+    /// The request did not get a response, e.g. because the host is unreachable.
+    Transport(String),
+}
+
+impl std::error::Error for ClientError {}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Save it to \"~/{SESSION_FILE}\" or set {SESSION_VAR}."
+            ),
+            ClientError::Status { code, .. } => write!(f, "request failed with status {code}."),
+            ClientError::Transport(message) => write!(f, "request failed: {message}"),
+        }
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    /// Where the time of the last request is kept, if requests are throttled.
+    state: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            state: None,
+        }
+    }
+
+    /// A client for the configured base URL and session, see the module documentation.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = session().ok_or(ClientError::MissingSession)?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self {
+            state: Some(PathBuf::from(STATE_PATH)),
+            ..Self::new(base_url, session)
+        })
+    }
+
+    /// The personal puzzle input of `day`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// The HTML page with the puzzle description of `day`, see [`crate::puzzle::to_markdown`].
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}"))
+    }
+
+    /// Posts `answer` for `part` of `day` and returns the HTML page of the reply, see
+    /// [`crate::submit::Response::parse`].
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        self.throttle();
+        let response = self
+            .agent
+            .post(&format!("{}/{year}/day/{day}/answer", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(response)
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        self.throttle();
+        let response = self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(response)
+    }
+
+    /// Waits until [`MIN_INTERVAL`] has passed since the last request and records this one.
+    fn throttle(&self) {
+        let Some(path) = &self.state else {
+            return;
+        };
+
+        let last = fs::read_to_string(path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+
+        let wait = remaining(last, since_epoch(), MIN_INTERVAL);
+        if !wait.is_zero() {
+            eprintln!("Waiting {wait:.1?} between requests...");
+            thread::sleep(wait);
+        }
+
+        // throttling is best effort, a missing state file only means that the next run does not wait.
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, since_epoch().as_millis().to_string());
+    }
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// How long to wait for `interval` to pass since `last`, both as durations since the epoch.
+fn remaining(last: Option<Duration>, now: Duration, interval: Duration) -> Duration {
+    last.map_or(Duration::ZERO, |last| (last + interval).saturating_sub(now))
+}
+
+/// When `day` unlocks: puzzles are released at midnight EST (UTC-5) on the day of December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    const EST_OFFSET: i64 = 5 * 3600;
+
+    let seconds = days_from_civil(year.into(), 12, day.into()) * 86_400 + EST_OFFSET;
+    match u64::try_from(seconds) {
+        Ok(seconds) => UNIX_EPOCH + Duration::from_secs(seconds),
+        Err(_) => UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs()),
+    }
+}
+
+/// How long until `day` unlocks, or `None` if it is already available.
+pub fn time_until_unlock(year: u16, day: u8) -> Option<Duration> {
+    unlock_time(year, day)
+        .duration_since(SystemTime::now())
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+/// Parses the value of `--year` for the commands that talk to the website, which has no events
+/// before [`FIRST_YEAR`].
+pub fn parse_year(value: &str) -> Result<u16, String> {
+    let year: u16 = value.parse().map_err(|e| format!("{e}"))?;
+    if year < FIRST_YEAR {
+        return Err(format!("the first Advent of Code was in {FIRST_YEAR}"));
+    }
+    Ok(year)
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar, negative before 1970.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+            code,
+            body: response.into_string().unwrap_or_default(),
+        }),
+        Err(e) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+/// The session cookie from the environment or the session file, if any.
+pub fn session() -> Option<String> {
+    let session = match env::var(SESSION_VAR) {
+        Ok(session) => session,
+        Err(_) => fs::read_to_string(session_file()?).ok()?,
+    };
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+fn session_file() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// A stand-in for the website that serves canned responses and records the requests it got.
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    pub struct Server {
+        pub base_url: String,
+        handle: JoinHandle<Vec<Request>>,
+    }
+
+    impl Server {
+        /// Waits until every response was served and returns the requests in order.
+        pub fn requests(self) -> Vec<Request> {
+            self.handle.join().unwrap()
+        }
+    }
+
+    /// Answers one request per `(status, body)` pair, then stops.
+    pub fn serve(responses: Vec<(u16, &str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let responses: Vec<(u16, String)> = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect();
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                requests.push(read_request(&mut reader));
+
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+
+            requests
+        });
+
+        Server { base_url, handle }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Request {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        let mut request_line = line.split_whitespace();
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().to_string();
+
+        let mut headers = vec![];
+        loop {
+            line.clear();
+            reader.read_line(&mut line).unwrap();
+            match line.trim_end().split_once(": ") {
+                Some((key, value)) => headers.push((key.to_string(), value.to_string())),
+                None => break,
+            }
+        }
+
+        let mut request = Request {
+            method,
+            path,
+            headers,
+            body: String::new(),
+        };
+
+        let length = request
+            .header("Content-Length")
+            .and_then(|length| length.parse().ok())
+            .unwrap_or(0);
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+        request.body = String::from_utf8_lossy(&body).into_owned();
+
+        request
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let server = mock::serve(vec![(200, "1721\n979\n")]);
+        let client = Client::new(&server.base_url, "secret");
+
+        assert_eq!(client.input(2020, 1).unwrap(), "1721\n979\n");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2020/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert!(requests[0]
+            .header("User-Agent")
+            .is_some_and(|agent| agent.starts_with("advent_of_code/")));
+    }
+
+    #[test]
+    fn test_status_error() {
+        let server = mock::serve(vec![(404, "Please don't repeatedly request this endpoint")]);
+        let client = Client::new(format!("{}/", server.base_url), "secret");
+
+        match client.puzzle(2020, 25) {
+            Err(ClientError::Status { code, body }) => {
+                assert_eq!(code, 404);
+                assert!(body.starts_with("Please don't"));
+            }
+            other => panic!("expected a status error, got {other:?}"),
+        }
+
+        assert_eq!(server.requests()[0].path, "/2020/day/25");
+    }
+
+    #[test]
+    fn test_remaining() {
+        let now = Duration::from_secs(100);
+        let interval = Duration::from_secs(3);
+
+        assert_eq!(remaining(None, now, interval), Duration::ZERO);
+        assert_eq!(
+            remaining(Some(Duration::from_secs(99)), now, interval),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            remaining(Some(Duration::from_secs(90)), now, interval),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        // 2020-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(2020, 1),
+            UNIX_EPOCH + Duration::from_secs(1_606_798_800)
+        );
+        assert_eq!(
+            unlock_time(2023, 25),
+            UNIX_EPOCH + Duration::from_secs(1_703_480_400)
+        );
+        assert_eq!(time_until_unlock(2020, 1), None);
+        assert_eq!(
+            unlock_time(1900, 1),
+            UNIX_EPOCH - Duration::from_secs(2_180_113_200)
+        );
+    }
+
+    #[test]
+    fn test_parse_year() {
+        assert_eq!(parse_year("2015"), Ok(2015));
+        assert!(parse_year("1900").is_err());
+        assert!(parse_year("twenty").is_err());
+    }
+}
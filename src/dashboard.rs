@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! The status of every day as shown by `cargo dashboard`, gathered from `src/days`, `src/inputs`,
+//! `src/examples`, `src/answers` and `src/puzzles`.
+use crate::{
+    answers::Answers, days, examples, file_path, runner::DayReport, watch, watch::PartRun,
+};
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+pub struct DayStatus {
+    pub year: u16,
+    pub day: u8,
+    /// The title of the solution, if it is registered and has one.
+    pub title: Option<&'static str>,
+    pub scaffolded: bool,
+    /// Whether the input exists and is not empty.
+    pub input: bool,
+    /// The number of examples that are not empty.
+    pub examples: usize,
+    pub puzzle: bool,
+    pub answers: Answers,
+    pub last_run: Option<LastRun>,
+}
+
+impl DayStatus {
+    pub fn load(year: u16, day: u8) -> Self {
+        let non_empty = |path: &Path| fs::metadata(path).is_ok_and(|file| file.len() > 0);
+
+        Self {
+            year,
+            day,
+            title: days::get(year, day)
+                .map(|registered| registered.title)
+                .filter(|title| !title.is_empty()),
+            scaffolded: watch::module_path(year, day).exists(),
+            input: non_empty(&file_path("inputs", year, day)),
+            // scaffolding creates an empty example before the puzzle is known.
+            examples: examples::find(year, day).map_or(0, |examples| {
+                examples
+                    .iter()
+                    .filter(|example| non_empty(&example.path))
+                    .count()
+            }),
+            puzzle: puzzle_path(year, day).exists(),
+            answers: Answers::load(year, day).unwrap_or_default(),
+            last_run: LastRun::load(year, day),
+        }
+    }
+
+    /// The 25 days of `year`.
+    pub fn all(year: u16) -> Vec<Self> {
+        (1..=25).map(|day| Self::load(year, day)).collect()
+    }
+}
+
+/// `src/puzzles/<year>/<day>.md`, as written by `cargo download`.
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    file_path("puzzles", year, day).with_extension("md")
+}
+
+/// The parts of the last run of a day from the dashboard, stored in
+/// `target/aoc-dashboard/<year>/<day>.jsonl` as the lines of `cargo solve --json`.
+#[derive(Debug, Default, PartialEq)]
+pub struct LastRun {
+    pub parts: Vec<PartRun>,
+}
+
+impl LastRun {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        env::current_dir()
+            .unwrap()
+            .join("target")
+            .join("aoc-dashboard")
+            .join(year.to_string())
+            .join(format!("{day:02}.jsonl"))
+    }
+
+    pub fn load(year: u16, day: u8) -> Option<Self> {
+        let contents = fs::read_to_string(Self::path(year, day)).ok()?;
+        Some(Self::parse(&contents))
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        Self {
+            parts: contents.lines().filter_map(PartRun::parse).collect(),
+        }
+    }
+
+    pub fn save(report: &DayReport) -> io::Result<()> {
+        let path = Self::path(report.year, report.day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let lines: String = report.json_lines().map(|line| line + "\n").collect();
+        fs::write(path, lines)
+    }
+
+    /// `Some(false)` if a part differs from its recorded answer, `Some(true)` if every part matches
+    /// its recorded answer, `None` otherwise.
+    pub fn verified(&self) -> Option<bool> {
+        let verdict = |status| {
+            self.parts
+                .iter()
+                .filter(|part| part.verdict.as_deref() == Some(status))
+                .count()
+        };
+
+        if verdict("incorrect") > 0 {
+            Some(false)
+        } else if !self.parts.is_empty() && verdict("correct") == self.parts.len() {
+            Some(true)
+        } else {
+            None
+        }
+    }
+
+    /// The total time of the solved parts, `None` if no part was solved.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.parts
+            .iter()
+            .filter_map(|part| part.elapsed)
+            .reduce(|a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(part: u8, nanos: &str, verdict: &str) -> String {
+        format!(
+            "{{\"year\":2020,\"day\":1,\"part\":{part},\"status\":\"solved\",\"answer\":\"1\",\"nanos\":{nanos},\"stats\":null,\"peak_bytes\":null,\"allocations\":null,\"verdict\":{verdict},\"expected\":null,\"message\":null}}\n"
+        )
+    }
+
+    #[test]
+    fn test_last_run() {
+        let run =
+            LastRun::parse(&(line(1, "1000", "\"correct\"") + &line(2, "500", "\"correct\"")));
+        assert_eq!(run.verified(), Some(true));
+        assert_eq!(run.elapsed(), Some(Duration::from_nanos(1500)));
+
+        let run =
+            LastRun::parse(&(line(1, "1000", "\"correct\"") + &line(2, "500", "\"unknown\"")));
+        assert_eq!(run.verified(), None);
+
+        let run = LastRun::parse(&(line(1, "null", "null") + &line(2, "500", "\"incorrect\"")));
+        assert_eq!(run.verified(), Some(false));
+        assert_eq!(run.elapsed(), Some(Duration::from_nanos(500)));
+
+        assert_eq!(LastRun::default().elapsed(), None);
+    }
+}
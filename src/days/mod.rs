@@ -0,0 +1,75 @@
+/*
+ * Every event lives in its own `yYYYY` module with its own `DAYS` registry and is listed in `YEARS` below.
+ * `INPUTS` collects the embedded inputs of every year when the `embed-inputs` feature is enabled.
+ * `cargo scaffold <day> --year <year>` creates and registers new years automatically.
+ */
+use crate::Day;
+use std::{
+    env,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+pub mod y2020;
+
+/// The registered solutions of a single event.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: u16, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+}
+
+#[rustfmt::skip]
+pub static YEARS: &[Year] = &[
+    Year::new(2020, y2020::DAYS),
+];
+
+/// Inputs compiled into the binaries with the `embed-inputs` feature.
+#[cfg(feature = "embed-inputs")]
+#[rustfmt::skip]
+pub static INPUTS: &[(u16, &[(u8, &str)])] = &[
+    (2020, y2020::INPUTS),
+];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|registered| registered.year == year)
+}
+
+pub fn get(year: u16, day: u8) -> Option<&'static Day> {
+    self::year(year)?
+        .days
+        .iter()
+        .find(|solution| solution.day == day)
+}
+
+/// Every registered day of every year.
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|year| year.days)
+}
+
+/// The year commands use when no `--year` is given: `AOC_YEAR` if set, otherwise the latest
+/// registered year, otherwise the current one.
+pub fn default_year() -> u16 {
+    if let Some(year) = env::var("AOC_YEAR").ok().and_then(|year| year.parse().ok()) {
+        return year;
+    }
+
+    YEARS
+        .iter()
+        .map(|year| year.year)
+        .max()
+        .unwrap_or_else(current_year)
+}
+
+fn current_year() -> u16 {
+    const SECONDS_PER_YEAR: u64 = 31_556_952;
+
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    1970 + (seconds / SECONDS_PER_YEAR) as u16
+}
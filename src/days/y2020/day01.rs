@@ -1,3 +1,4 @@
+use crate::Solution;
 use itertools::Itertools;
 use std::collections::HashSet;
 
@@ -30,10 +31,23 @@ pub fn part_two(input: &str) -> Option<u32> {
         .next()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day01;
+
+impl Solution for Day01 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<u32>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Report Repair";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -42,13 +56,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = crate::read_file("examples", 2020, 1);
         assert_eq!(part_one(&input), Some(514579));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = crate::read_file("examples", 2020, 1);
         assert_eq!(part_two(&input), Some(241861950));
     }
 }
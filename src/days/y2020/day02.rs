@@ -0,0 +1,168 @@
+use crate::{
+    parse::{numbered_lines, ParseError},
+    Solution,
+};
+
+struct PasswordPolicy<'a> {
+    pub password: String,
+    pub character: String,
+    pub first: usize,
+    pub second: usize,
+    /// The line the policy was parsed from and its number, for errors about the positions.
+    number: usize,
+    line: &'a str,
+    tokens: [&'a str; 2],
+}
+
+impl PasswordPolicy<'_> {
+    /// Whether the character is at the first (`0`) or second (`1`) position. The positions are
+    /// 1-based, and an error if they are outside the password.
+    fn matches_at(&self, index: usize) -> Result<bool, ParseError> {
+        let (position, token) = ([self.first, self.second][index], self.tokens[index]);
+
+        if !(1..=self.password.len()).contains(&position) {
+            return Err(ParseError::at(
+                self.number,
+                self.line,
+                token,
+                format!(
+                    "expected a position from 1 to {}, found {position}",
+                    self.password.len()
+                ),
+            ));
+        }
+
+        Ok(self.password.as_bytes()[position - 1] == self.character.as_bytes()[0])
+    }
+}
+
+fn parse_line(number: usize, line: &str) -> Result<PasswordPolicy<'_>, ParseError> {
+    let error = |token: &str, message: String| ParseError::at(number, line, token, message);
+
+    let (requirement, password) = line.split_once(": ").ok_or_else(|| {
+        ParseError::new(
+            number,
+            "expected \"<first>-<second> <character>: <password>\"",
+        )
+    })?;
+    let (occurrences, character) = requirement
+        .split_once(' ')
+        .ok_or_else(|| error(requirement, "expected a character after the range".into()))?;
+    let (first, second) = occurrences
+        .split_once('-')
+        .ok_or_else(|| error(occurrences, "expected \"<first>-<second>\"".into()))?;
+
+    let parse_number = |token: &str| {
+        token
+            .parse()
+            .map_err(|_| error(token, format!("expected a number, found \"{token}\"")))
+    };
+
+    if character.chars().count() != 1 {
+        return Err(error(
+            character,
+            format!("expected a single character, found \"{character}\""),
+        ));
+    }
+
+    Ok(PasswordPolicy {
+        password: password.to_string(),
+        character: character.to_string(),
+        first: parse_number(first)?,
+        second: parse_number(second)?,
+        number,
+        line,
+        tokens: [first, second],
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<PasswordPolicy<'_>>, ParseError> {
+    numbered_lines(input)
+        .map(|(number, line)| parse_line(number, line))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    Ok(parse_input(input)?
+        .iter()
+        .filter(|password_policy| {
+            let occurrences = password_policy
+                .password
+                .matches(&password_policy.character)
+                .count();
+
+            (password_policy.first..=password_policy.second).contains(&occurrences)
+        })
+        .count() as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    parse_input(input)?
+        .iter()
+        .try_fold(0, |valid, password_policy| {
+            // exactly one of the positions has to contain the character.
+            let first_character_matches = password_policy.matches_at(0)?;
+            let second_character_matches = password_policy.matches_at(1)?;
+
+            Ok(valid + u32::from(first_character_matches != second_character_matches))
+        })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type PartOne = Result<u32, ParseError>;
+    type PartTwo = Result<u32, ParseError>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Password Philosophy";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2020, 2);
+        assert_eq!(part_one(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 2020, 2);
+        assert_eq!(part_two(&input), Ok(1));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let error = part_one("1-3 a: abcde\n1-x b: cdefg").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad input at line 2, column 3: expected a number, found \"x\""
+        );
+
+        assert_eq!(part_one("0-3 a: bcd\n2-9 c: ccccc"), Ok(2));
+
+        let error = part_two("0-3 a: abcde").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad input at line 1, column 1: expected a position from 1 to 5, found 0"
+        );
+
+        let error = part_two("1-3 a: abcde\n1-30 a: abcde").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "bad input at line 2, column 3: expected a position from 1 to 5, found 30"
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::Solution;
+
 struct Slope(usize, usize);
 
 #[derive(Copy, Clone, Debug)]
@@ -87,10 +89,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day03;
+
+impl Solution for Day03 {
+    type PartOne = Option<u32>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Toboggan Trajectory";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -99,13 +114,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = crate::read_file("examples", 2020, 3);
         assert_eq!(part_one(&input), Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = crate::read_file("examples", 2020, 3);
         assert_eq!(part_two(&input), Some(336));
     }
 }
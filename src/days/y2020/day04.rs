@@ -1,5 +1,7 @@
-use itertools::Itertools;
-use std::num::ParseIntError;
+use crate::{
+    parse::{numbered_lines, ParseError},
+    Solution,
+};
 
 #[derive(Debug)]
 enum PassportField {
@@ -10,45 +12,54 @@ enum PassportField {
     HairColor(String),
     EyeColor(String),
     PassportID(String),
-    CountryID(String),
 }
 
-fn parse_input(input: &str) -> Vec<Vec<PassportField>> {
-    input
-        .split("\n\n")
-        .map(|passport_input| {
-            passport_input
-                .split("\n")
-                .flat_map(|line| line.split(" "))
-                .filter_map(|field| {
-                    let mut data = field.split(":");
-                    let field_id = data.next().unwrap();
-                    let entry = data.next().unwrap().to_string();
-
-                    match field_id {
-                        "byr" => Some(PassportField::BirthYear(entry)),
-                        "iyr" => Some(PassportField::IssueYear(entry)),
-                        "eyr" => Some(PassportField::ExpirationYear(entry)),
-                        "hgt" => Some(PassportField::Height(entry)),
-                        "hcl" => Some(PassportField::HairColor(entry)),
-                        "ecl" => Some(PassportField::EyeColor(entry)),
-                        "pid" => Some(PassportField::PassportID(entry)),
-                        "cid" => None,
-                        &_ => panic!("{:?}", field_id),
-                    }
-                })
-                .collect()
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<Vec<PassportField>>, ParseError> {
+    let mut passports = vec![vec![]];
+
+    for (number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            passports.push(vec![]);
+            continue;
+        }
+
+        for field in line.split_whitespace() {
+            let (field_id, entry) = field.split_once(':').ok_or_else(|| {
+                ParseError::at(number, line, field, "expected \"<field>:<value>\"")
+            })?;
+            let entry = entry.to_string();
+
+            let field = match field_id {
+                "byr" => PassportField::BirthYear(entry),
+                "iyr" => PassportField::IssueYear(entry),
+                "eyr" => PassportField::ExpirationYear(entry),
+                "hgt" => PassportField::Height(entry),
+                "hcl" => PassportField::HairColor(entry),
+                "ecl" => PassportField::EyeColor(entry),
+                "pid" => PassportField::PassportID(entry),
+                "cid" => continue,
+                _ => {
+                    return Err(ParseError::at(
+                        number,
+                        line,
+                        field_id,
+                        format!("unknown field \"{field_id}\""),
+                    ))
+                }
+            };
+
+            passports.last_mut().unwrap().push(field);
+        }
+    }
+
+    Ok(passports)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        parse_input(input)
-            .into_iter()
-            .filter(|fields| fields.len() == 7)
-            .count() as u32,
-    )
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    Ok(parse_input(input)?
+        .into_iter()
+        .filter(|fields| fields.len() == 7)
+        .count() as u32)
 }
 
 fn is_valid_passport(fields: &[PassportField]) -> bool {
@@ -93,26 +104,36 @@ fn is_valid_passport(fields: &[PassportField]) -> bool {
                 })
         }
         PassportField::EyeColor(color) => {
-            vec!["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&&**color)
+            ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&&**color)
         }
         PassportField::PassportID(id) => id.len() == 9 && id.parse::<usize>().is_ok(),
-        PassportField::CountryID(_) => true,
     })
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        parse_input(input)
-            .into_iter()
-            .filter(|fields| is_valid_passport(fields))
-            .count() as u32,
-    )
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    Ok(parse_input(input)?
+        .into_iter()
+        .filter(|fields| is_valid_passport(fields))
+        .count() as u32)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day04;
+
+impl Solution for Day04 {
+    type PartOne = Result<u32, ParseError>;
+    type PartTwo = Result<u32, ParseError>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Passport Processing";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -121,8 +142,8 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
+        let input = crate::read_file("examples", 2020, 4);
+        assert_eq!(part_one(&input), Ok(2));
     }
 
     #[test]
@@ -135,7 +156,7 @@ mod tests {
         ];
 
         for passport in passports {
-            let fields = parse_input(passport);
+            let fields = parse_input(passport).unwrap();
             assert!(!is_valid_passport(&fields[0]))
         }
     }
@@ -150,8 +171,21 @@ mod tests {
         ];
 
         for passport in passports {
-            let fields = parse_input(passport);
+            let fields = parse_input(passport).unwrap();
             assert!(is_valid_passport(&fields[0]))
         }
     }
+
+    #[test]
+    fn test_unknown_field() {
+        let input = "ecl:gry pid:860033327\n\niyr:2013 ecl:amb xyz:350";
+        assert_eq!(
+            part_one(input),
+            Err(ParseError {
+                line: 3,
+                column: Some(18),
+                message: "unknown field \"xyz\"".into(),
+            })
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::Solution;
 use std::collections::HashSet;
 
 fn binary_search(min: usize, max: usize, left: char, right: char, sequence: Vec<char>) -> usize {
@@ -8,7 +9,7 @@ fn binary_search(min: usize, max: usize, left: char, right: char, sequence: Vec<
         if char == left {
             max = (min + max) / 2;
         } else if char == right {
-            min = (min + max + 1) / 2;
+            min = (min + max).div_ceil(2);
         } else {
             panic!("{}", char);
         }
@@ -32,15 +33,15 @@ impl BoardingPass {
         let column = binary_search(0, 7, 'L', 'R', chars.clone().skip(7).take(3).collect());
 
         Self {
-            row: row.clone(),
-            column: column.clone(),
+            row,
+            column,
             seat_id: row * 8 + column,
         }
     }
 }
 
 fn parse_input(input: &str) -> Vec<BoardingPass> {
-    input.lines().map(|line| BoardingPass::new(line)).collect()
+    input.lines().map(BoardingPass::new).collect()
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -60,14 +61,26 @@ pub fn part_two(input: &str) -> Option<usize> {
 
     (1..highest_seat_id)
         .filter(|seat_id| !seat_ids.contains(seat_id))
-        .filter(|seat_id| seat_ids.contains(&(seat_id - 1)) && seat_ids.contains(&(seat_id + 1)))
-        .next()
+        .find(|seat_id| seat_ids.contains(&(seat_id - 1)) && seat_ids.contains(&(seat_id + 1)))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day05;
+
+impl Solution for Day05 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Binary Boarding";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
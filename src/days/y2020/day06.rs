@@ -1,3 +1,5 @@
+use crate::Solution;
+
 const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
 
 struct AnswerSet {
@@ -63,7 +65,7 @@ pub fn part_one(input: &str) -> Option<usize> {
             .map(|group| {
                 group
                     .lines()
-                    .map(|line| AnswerSet::from_string(line))
+                    .map(AnswerSet::from_string)
                     .fold(AnswerSet::no(), |mut current, other| {
                         current.union(&other);
                         current
@@ -81,7 +83,7 @@ pub fn part_two(input: &str) -> Option<usize> {
             .map(|group| {
                 group
                     .lines()
-                    .map(|line| AnswerSet::from_string(line))
+                    .map(AnswerSet::from_string)
                     .fold(AnswerSet::yes(), |mut current, other| {
                         current.remain_overlap(&other);
                         current
@@ -92,10 +94,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day06;
+
+impl Solution for Day06 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Custom Customs";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -104,13 +119,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = crate::read_file("examples", 2020, 6);
         assert_eq!(part_one(&input), Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = crate::read_file("examples", 2020, 6);
         assert_eq!(part_two(&input), Some(6));
     }
 }
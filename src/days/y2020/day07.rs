@@ -1,3 +1,4 @@
+use crate::Solution;
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 
@@ -20,8 +21,7 @@ fn parse_rules(input: &str, bags: &HashMap<String, Bag>) -> HashMap<Bag, Vec<(Ba
 
             let target_bag_indices = line
                 .split("contain ")
-                .skip(1)
-                .next()
+                .nth(1)
                 .unwrap()
                 .split(",")
                 .filter_map(|data| {
@@ -65,7 +65,7 @@ pub fn part_one(input: &str) -> Option<usize> {
             .map(|(bag, _)| contains_shiny_gold.get(bag).cloned())
             .collect::<Vec<Option<bool>>>();
 
-        if other_bags.iter().any(|element| *element == Some(true)) {
+        if other_bags.contains(&Some(true)) {
             contains_shiny_gold.insert(bag, true);
             continue;
         }
@@ -116,10 +116,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(*bags_inside.get(bags.get("shiny gold").unwrap()).unwrap() - 1)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day07;
+
+impl Solution for Day07 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Handy Haversacks";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -128,13 +141,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 2020, 7);
         assert_eq!(part_one(&input), Some(4));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = crate::read_file("examples", 2020, 7);
         assert_eq!(part_two(&input), Some(32));
     }
 
@@ -147,6 +160,6 @@ dark yellow bags contain 2 dark green bags.
 dark green bags contain 2 dark blue bags.
 dark blue bags contain 2 dark violet bags.
 dark violet bags contain no other bags.";
-        assert_eq!(part_two(&input), Some(126));
+        assert_eq!(part_two(input), Some(126));
     }
 }
@@ -1,5 +1,6 @@
-use crate::Instruction::{Accumulator, Jump, NoOperation};
-use std::collections::{HashMap, HashSet, VecDeque};
+use self::Instruction::{Accumulator, Jump, NoOperation};
+use crate::Solution;
+use std::collections::HashSet;
 
 #[derive(Debug)]
 enum Instruction {
@@ -32,7 +33,7 @@ fn parse_input(input: &str) -> Vec<Instruction> {
 }
 
 fn step(
-    instructions: &Vec<Instruction>,
+    instructions: &[Instruction],
     accumulator: &mut isize,
     index: &mut isize,
     executed_instructions: &mut HashSet<isize>,
@@ -54,7 +55,7 @@ fn step(
 }
 
 fn simulate(
-    instructions: &Vec<Instruction>,
+    instructions: &[Instruction],
     accumulator: isize,
     index: isize,
     executed_instructions: &mut HashSet<isize>,
@@ -132,10 +133,23 @@ pub fn part_two(input: &str) -> Option<isize> {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day08;
+
+impl Solution for Day08 {
+    type PartOne = Option<isize>;
+    type PartTwo = Option<isize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Handheld Halting";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -144,13 +158,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = crate::read_file("examples", 2020, 8);
         assert_eq!(part_one(&input), Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = crate::read_file("examples", 2020, 8);
         assert_eq!(part_two(&input), Some(8));
     }
 }
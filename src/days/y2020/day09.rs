@@ -1,6 +1,7 @@
+use crate::Solution;
 use itertools::Itertools;
 use std::cmp::{max, min, Ordering};
-use std::collections::{HashSet, VecDeque};
+use std::collections::VecDeque;
 
 fn solve_part_one(input: &str, preamble_size: usize) -> Option<usize> {
     let input: Vec<usize> = input
@@ -10,9 +11,9 @@ fn solve_part_one(input: &str, preamble_size: usize) -> Option<usize> {
 
     let mut previous_values: VecDeque<usize> = input.iter().take(preamble_size).copied().collect();
 
-    let mut message = input.into_iter().skip(preamble_size);
+    let message = input.into_iter().skip(preamble_size);
 
-    while let Some(next) = message.next() {
+    for next in message {
         if !previous_values
             .iter()
             .tuple_combinations()
@@ -48,9 +49,9 @@ fn solve_part_two(input: &str, preamble_size: usize) -> Option<usize> {
             let mut minimum = start;
             let mut maximum = start;
 
-            let mut remaining_values = &mut input[index + 1..].iter();
+            let remaining_values = &mut input[index + 1..].iter();
 
-            while let Some(&next) = remaining_values.next() {
+            for &next in remaining_values.by_ref() {
                 total += next;
                 minimum = min(minimum, next);
                 maximum = max(maximum, next);
@@ -71,10 +72,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     solve_part_two(input, 25)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day09;
+
+impl Solution for Day09 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Encoding Error";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -83,13 +97,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 2020, 9);
         assert_eq!(solve_part_one(&input, 5), Some(127));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = crate::read_file("examples", 2020, 9);
         assert_eq!(solve_part_two(&input, 5), Some(62));
     }
 }
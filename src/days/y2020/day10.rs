@@ -1,3 +1,4 @@
+use crate::Solution;
 use itertools::Itertools;
 use std::iter;
 
@@ -60,10 +61,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     dynamic_program.last().copied()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day10;
+
+impl Solution for Day10 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Adapter Array";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -72,7 +86,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = crate::read_file("examples", 2020, 10);
         assert_eq!(part_one(&input), Some(35));
     }
 }
@@ -1,3 +1,4 @@
+use crate::Solution;
 use bitvector::*;
 
 #[derive(Debug)]
@@ -88,7 +89,7 @@ pub fn part_one(input: &str) -> Option<usize> {
     let adjacent_seat_masks: Vec<(usize, BitVector)> = (0..ferry.height)
         .flat_map(move |y| (0..ferry.width).map(move |x| (x, y)))
         .filter_map(|(x, y)| {
-            let index = ferry.index(x as usize, y as usize);
+            let index = ferry.index(x, y);
 
             if !ferry.seats.contains(index) {
                 return None;
@@ -142,7 +143,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     let adjacent_seat_masks: Vec<(usize, BitVector)> = (0..ferry.height)
         .flat_map(move |y| (0..ferry.width).map(move |x| (x, y)))
         .filter_map(|(x, y)| {
-            let index = ferry.index(x as usize, y as usize);
+            let index = ferry.index(x, y);
 
             if !ferry.seats.contains(index) {
                 return None;
@@ -197,7 +198,7 @@ pub fn part_two(input: &str) -> Option<usize> {
                 assert!(mask.insert(index));
             }
 
-            Some((ferry.index(x as usize, y as usize), mask))
+            Some((ferry.index(x, y), mask))
         })
         .collect();
 
@@ -206,10 +207,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(ferry.occupied_seats.len())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day11;
+
+impl Solution for Day11 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Seating System";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -218,13 +232,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 2020, 11);
         assert_eq!(part_one(&input), Some(37));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = crate::read_file("examples", 2020, 11);
         assert_eq!(part_two(&input), Some(26));
     }
 }
@@ -1,3 +1,8 @@
+use crate::{
+    parse::{numbered_lines, ParseError},
+    Solution,
+};
+
 #[derive(Debug, Clone, Copy)]
 enum Direction {
     North,
@@ -103,39 +108,63 @@ impl RelativePosition {
     }
 }
 
-fn parse_input(input: &str) -> impl Iterator<Item = Action> + '_ {
-    input.lines().map(|line| {
-        let mut chars = line.chars();
-        let action = chars.next().unwrap();
-        let number = chars
-            .into_iter()
-            .collect::<String>()
-            .parse::<isize>()
-            .unwrap();
-
-        match (action, number) {
-            // Turn actions
-            ('L', 90) => Action::Turn(Turn::Left),
-            ('L', 180) => Action::Turn(Turn::Around),
-            ('L', 270) => Action::Turn(Turn::Right),
-            ('R', 90) => Action::Turn(Turn::Right),
-            ('R', 180) => Action::Turn(Turn::Around),
-            ('R', 270) => Action::Turn(Turn::Left),
-
-            // Move actions
-            ('N', number) => Action::Move(Direction::North, number),
-            ('S', number) => Action::Move(Direction::South, number),
-            ('E', number) => Action::Move(Direction::East, number),
-            ('W', number) => Action::Move(Direction::West, number),
-            ('F', number) => Action::Forward(number),
-
-            _ => panic!("Unknown action '{action}' '{number}'"),
+fn parse_line(number: usize, line: &str) -> Result<Action, ParseError> {
+    let Some(action) = line.chars().next() else {
+        return Err(ParseError::new(number, "expected an action"));
+    };
+    let value = &line[action.len_utf8()..];
+    let amount = value.parse::<isize>().map_err(|_| {
+        ParseError::at(
+            number,
+            line,
+            value,
+            format!("expected a number, found \"{value}\""),
+        )
+    })?;
+
+    Ok(match (action, amount) {
+        // Turn actions
+        ('L', 90) => Action::Turn(Turn::Left),
+        ('L', 180) => Action::Turn(Turn::Around),
+        ('L', 270) => Action::Turn(Turn::Right),
+        ('R', 90) => Action::Turn(Turn::Right),
+        ('R', 180) => Action::Turn(Turn::Around),
+        ('R', 270) => Action::Turn(Turn::Left),
+        ('L' | 'R', _) => {
+            return Err(ParseError::at(
+                number,
+                line,
+                value,
+                format!("cannot turn by {amount} degrees"),
+            ))
+        }
+
+        // Move actions
+        ('N', amount) => Action::Move(Direction::North, amount),
+        ('S', amount) => Action::Move(Direction::South, amount),
+        ('E', amount) => Action::Move(Direction::East, amount),
+        ('W', amount) => Action::Move(Direction::West, amount),
+        ('F', amount) => Action::Forward(amount),
+
+        _ => {
+            return Err(ParseError::at(
+                number,
+                line,
+                line,
+                format!("unknown action '{action}'"),
+            ))
         }
     })
 }
 
-pub fn part_one(input: &str) -> Option<isize> {
-    let (_, ship) = parse_input(input).fold(
+fn parse_input(input: &str) -> Result<Vec<Action>, ParseError> {
+    numbered_lines(input)
+        .map(|(number, line)| parse_line(number, line))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<isize, ParseError> {
+    let (_, ship) = parse_input(input)?.into_iter().fold(
         (Direction::East, Position::new(0, 0)),
         |(direction, ship), action| match action {
             Action::Turn(turn) => (direction.turn(turn), ship),
@@ -146,11 +175,11 @@ pub fn part_one(input: &str) -> Option<isize> {
         },
     );
 
-    Some(ship.x.abs() + ship.y.abs())
+    Ok(ship.x.abs() + ship.y.abs())
 }
 
-pub fn part_two(input: &str) -> Option<isize> {
-    let (ship, _) = parse_input(input).fold(
+pub fn part_two(input: &str) -> Result<isize, ParseError> {
+    let (ship, _) = parse_input(input)?.into_iter().fold(
         (Position::new(0, 0), RelativePosition::new(10, 1)),
         |(ship, waypoint), action| match action {
             Action::Turn(turn) => (ship, waypoint.turn(turn)),
@@ -159,13 +188,26 @@ pub fn part_two(input: &str) -> Option<isize> {
         },
     );
 
-    Some(ship.x.abs() + ship.y.abs())
+    Ok(ship.x.abs() + ship.y.abs())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type PartOne = Result<isize, ParseError>;
+    type PartTwo = Result<isize, ParseError>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Rain Risk";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -174,13 +216,25 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_one(&input), Some(25));
+        let input = crate::read_file("examples", 2020, 12);
+        assert_eq!(part_one(&input), Ok(25));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12);
-        assert_eq!(part_two(&input), Some(286));
+        let input = crate::read_file("examples", 2020, 12);
+        assert_eq!(part_two(&input), Ok(286));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert_eq!(
+            part_one("F10\nQ3").unwrap_err().to_string(),
+            "bad input at line 2, column 1: unknown action 'Q'"
+        );
+        assert_eq!(
+            part_two("F10\nR45").unwrap_err().to_string(),
+            "bad input at line 2, column 2: cannot turn by 45 degrees"
+        );
     }
 }
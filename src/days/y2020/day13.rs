@@ -1,3 +1,5 @@
+use crate::Solution;
+
 pub fn part_one(input: &str) -> Option<usize> {
     let mut lines = input.lines();
     let departure_time = lines.next().unwrap().parse::<usize>().unwrap();
@@ -20,8 +22,7 @@ pub fn part_one(input: &str) -> Option<usize> {
 pub fn part_two(input: &str) -> Option<usize> {
     let busses = input
         .lines()
-        .skip(1)
-        .next()
+        .nth(1)
         .unwrap()
         .split(",")
         .enumerate()
@@ -44,10 +45,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(current)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day13;
+
+impl Solution for Day13 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Shuttle Search";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -56,22 +70,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 2020, 13);
         assert_eq!(part_one(&input), Some(295));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = crate::read_file("examples", 2020, 13);
         assert_eq!(part_two(&input), Some(1068781));
     }
-
-    #[test]
-    fn test_part_two_examples() {
-        assert_eq!(part_two("1\n17,x,13,19"), Some(3417));
-        assert_eq!(part_two("1\n17,x,13,19"), Some(754018));
-        assert_eq!(part_two("1\n17,x,13,19"), Some(779210));
-        assert_eq!(part_two("1\n17,x,13,19"), Some(1261476));
-        assert_eq!(part_two("1\n17,x,13,19"), Some(1202161486));
-    }
 }
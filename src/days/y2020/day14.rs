@@ -0,0 +1,221 @@
+use crate::{
+    parse::{numbered_lines, ParseError},
+    Solution,
+};
+use rustc_hash::FxHashMap;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum BitState {
+    Zero,
+    One,
+    Floating,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BitMask([BitState; 36]);
+
+impl BitMask {
+    pub fn with_indices(&self) -> impl Iterator<Item = (usize, BitState)> + '_ {
+        self.0.iter().rev().copied().enumerate()
+    }
+
+    pub fn indices_of_type(&self, t: BitState) -> impl Iterator<Item = usize> + '_ {
+        self.with_indices()
+            .filter(move |(_, element)| *element == t)
+            .map(|(index, _)| index)
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Mask(BitMask),
+    Write { address: usize, value: usize },
+}
+
+fn parse_line(number: usize, line: &str) -> Result<Instruction, ParseError> {
+    let (instruction, value) = line.split_once(" = ").ok_or_else(|| {
+        ParseError::new(
+            number,
+            "expected \"mask = <mask>\" or \"mem[<address>] = <value>\"",
+        )
+    })?;
+    let number_error = |token: &str| {
+        ParseError::at(
+            number,
+            line,
+            token,
+            format!("expected a number, found \"{token}\""),
+        )
+    };
+
+    if let Some(address) = instruction
+        .strip_prefix("mem[")
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return Ok(Instruction::Write {
+            address: address.parse().map_err(|_| number_error(address))?,
+            value: value.parse().map_err(|_| number_error(value))?,
+        });
+    }
+
+    if instruction != "mask" {
+        return Err(ParseError::at(
+            number,
+            line,
+            instruction,
+            format!("unknown instruction \"{instruction}\""),
+        ));
+    }
+
+    let bits = value
+        .char_indices()
+        .map(|(index, char)| match char {
+            '0' => Ok(BitState::Zero),
+            '1' => Ok(BitState::One),
+            'X' => Ok(BitState::Floating),
+            _ => Err(ParseError::at(
+                number,
+                line,
+                &value[index..],
+                format!("unexpected bit state '{char}'"),
+            )),
+        })
+        .collect::<Result<Vec<BitState>, _>>()?;
+    let bit_count = bits.len();
+
+    let bits = bits.try_into().map_err(|_| {
+        ParseError::at(
+            number,
+            line,
+            value,
+            format!("expected a mask of 36 bits, found {bit_count}"),
+        )
+    })?;
+
+    Ok(Instruction::Mask(BitMask(bits)))
+}
+
+/// Parses the program and returns its initial mask and the remaining instructions.
+fn parse_input(input: &str) -> Result<(BitMask, Vec<Instruction>), ParseError> {
+    let mut instructions = numbered_lines(input).map(|(number, line)| parse_line(number, line));
+
+    let mask = match instructions.next().transpose()? {
+        Some(Instruction::Mask(mask)) => mask,
+        _ => {
+            return Err(ParseError::new(
+                1,
+                "expected the initial instruction to be a mask",
+            ))
+        }
+    };
+
+    Ok((mask, instructions.collect::<Result<_, _>>()?))
+}
+
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let (mut mask, instructions) = parse_input(input)?;
+    let mut memory: FxHashMap<usize, usize> = FxHashMap::default();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(updated_mask) => mask = updated_mask,
+            Instruction::Write { address, value } => {
+                // Binary modifications to decimal numbers based on https://stackoverflow.com/questions/74162324
+                let mut mutated_value = value;
+
+                for (index, state) in mask.with_indices() {
+                    match state {
+                        BitState::Zero => mutated_value &= !(1 << index),
+                        BitState::One => mutated_value |= 1 << index,
+                        BitState::Floating => {}
+                    }
+                }
+
+                memory.insert(address, mutated_value);
+            }
+        }
+    }
+
+    Ok(memory.values().sum())
+}
+
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let (mut mask, instructions) = parse_input(input)?;
+    let mut memory: FxHashMap<usize, usize> = FxHashMap::default();
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mask(updated_mask) => mask = updated_mask,
+            Instruction::Write { address, value } => {
+                // First apply the mask to the address.
+                let mut mutated_address = address;
+
+                // If the bitmask bit is 0, the corresponding memory address bit is unchanged.
+                // If the bitmask bit is 1, the corresponding memory address bit is overwritten with 1.
+                for index in mask.indices_of_type(BitState::One) {
+                    mutated_address |= 1 << index;
+                }
+
+                // Then change the floating values in both zeros as ones.
+                let addresses = mask.indices_of_type(BitState::Floating).fold(
+                    vec![mutated_address],
+                    |acc, index| {
+                        acc.into_iter()
+                            .flat_map(|address| [address | (1 << index), address & !(1 << index)])
+                            .collect()
+                    },
+                );
+
+                // Finally, store the value in all the memory locations.
+                for address in addresses {
+                    memory.insert(address, value);
+                }
+            }
+        }
+    }
+
+    Ok(memory.values().sum())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type PartOne = Result<usize, ParseError>;
+    type PartTwo = Result<usize, ParseError>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Docking Data";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 2020, 14);
+        assert_eq!(part_one(&input), Ok(165));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X";
+        assert_eq!(
+            part_one(input).unwrap_err().to_string(),
+            "bad input at line 3, column 42: unexpected bit state '2'"
+        );
+        assert_eq!(
+            part_one("mem[8] = 11").unwrap_err().to_string(),
+            "bad input at line 1: expected the initial instruction to be a mask"
+        );
+    }
+}
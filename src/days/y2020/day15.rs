@@ -1,3 +1,4 @@
+use crate::Solution;
 use rustc_hash::FxHashMap;
 
 pub struct History {
@@ -51,35 +52,21 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(solve(input, 30000000))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day15;
+
+impl Solution for Day15 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Rambunctious Recitation";
 
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one(&"0,3,6"), Some(436));
-        assert_eq!(part_one(&"1,3,2"), Some(1));
-        assert_eq!(part_one(&"2,1,3"), Some(10));
-        assert_eq!(part_one(&"1,2,3"), Some(27));
-        assert_eq!(part_one(&"2,3,1"), Some(78));
-        assert_eq!(part_one(&"3,2,1"), Some(438));
-        assert_eq!(part_one(&"3,1,2"), Some(1836));
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
     }
 
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two(&"0,3,6"), Some(175594));
-        assert_eq!(part_two(&"1,3,2"), Some(2578));
-        assert_eq!(part_two(&"2,1,3"), Some(3544142));
-        assert_eq!(part_two(&"1,2,3"), Some(261214));
-        assert_eq!(part_two(&"2,3,1"), Some(6895259));
-        assert_eq!(part_two(&"3,2,1"), Some(18));
-        assert_eq!(part_two(&"3,1,2"), Some(362));
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
     }
 }
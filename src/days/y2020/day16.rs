@@ -1,8 +1,12 @@
+use crate::Solution;
 use arrayvec::ArrayVec;
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 
-fn parse_input(input: &str) -> (Vec<Vec<(usize, usize)>>, Vec<usize>, Vec<Vec<usize>>) {
+type Field = Vec<(usize, usize)>;
+type Ticket = Vec<usize>;
+
+fn parse_input(input: &str) -> (Vec<Field>, Ticket, Vec<Ticket>) {
     let mut input = input.split("\n\n");
 
     let fields = input
@@ -11,8 +15,7 @@ fn parse_input(input: &str) -> (Vec<Vec<(usize, usize)>>, Vec<usize>, Vec<Vec<us
         .lines()
         .map(|line| {
             line.split(": ")
-                .skip(1)
-                .next()
+                .nth(1)
                 .unwrap()
                 .split(" or ")
                 .map(|range| {
@@ -30,8 +33,7 @@ fn parse_input(input: &str) -> (Vec<Vec<(usize, usize)>>, Vec<usize>, Vec<Vec<us
         .next()
         .unwrap()
         .lines()
-        .skip(1)
-        .next()
+        .nth(1)
         .unwrap()
         .split(",")
         .map(|element| element.parse::<usize>().unwrap())
@@ -59,16 +61,13 @@ pub fn part_one(input: &str) -> Option<usize> {
         nearby_tickets
             .into_iter()
             .filter_map(|ticket| {
-                ticket
-                    .into_iter()
-                    .filter(|element| {
-                        fields.iter().all(|field| {
-                            field
-                                .iter()
-                                .all(|(start, end)| element < start || element > end)
-                        })
+                ticket.into_iter().find(|element| {
+                    fields.iter().all(|field| {
+                        field
+                            .iter()
+                            .all(|(start, end)| element < start || element > end)
                     })
-                    .next()
+                })
             })
             .sum(),
     )
@@ -160,10 +159,23 @@ pub fn part_two<const SIZE: usize>(input: &str) -> Option<usize> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, solve_part_two, input);
+pub struct Day16;
+
+impl Solution for Day16 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Ticket Translation";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        solve_part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -172,13 +184,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16);
+        let input = crate::read_file("examples", 2020, 16);
         assert_eq!(part_one(&input), Some(71));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 16);
+        let input = crate::read_file("examples", 2020, 16);
         assert_eq!(part_two::<3>(&input), Some(98));
     }
 }
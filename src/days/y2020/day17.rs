@@ -1,3 +1,4 @@
+use crate::Solution;
 use rustc_hash::FxHashSet;
 
 type Scalar = i8;
@@ -202,10 +203,23 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(active_cubes.len())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub struct Day17;
+
+impl Solution for Day17 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Conway Cubes";
+
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
+    }
+
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
+    }
 }
 
 #[cfg(test)]
@@ -214,13 +228,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = crate::read_file("examples", 2020, 17);
         assert_eq!(part_one(&input), Some(112));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = crate::read_file("examples", 2020, 17);
         assert_eq!(part_two(&input), Some(848));
     }
 }
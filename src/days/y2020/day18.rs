@@ -1,3 +1,4 @@
+use crate::Solution;
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Copy)]
@@ -14,11 +15,11 @@ enum Expression {
 }
 
 fn parse_expression(input: &str) -> Expression {
-    let mut characters = input.chars().filter(|char| char != &' ').peekable();
+    let characters = input.chars().filter(|char| char != &' ').peekable();
     let mut stack: VecDeque<Vec<Expression>> = VecDeque::new();
     let mut expressions: Vec<Expression> = Vec::new();
 
-    while let Some(char) = characters.next() {
+    for char in characters {
         match char {
             '(' => {
                 stack.push_back(expressions);
@@ -79,8 +80,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(
         input
             .lines()
-            .map(|line| parse_expression(line))
-            .map(|expression| solve_expression(expression))
+            .map(parse_expression)
+            .map(solve_expression)
             .sum(),
     )
 }
@@ -125,43 +126,28 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(
         input
             .lines()
-            .map(|line| parse_expression(line))
-            .map(|expression| process_expression(expression))
-            .map(|expression| solve_expression(expression))
+            .map(parse_expression)
+            .map(process_expression)
+            .map(solve_expression)
             .sum(),
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 18);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+pub struct Day18;
+
+impl Solution for Day18 {
+    type PartOne = Option<usize>;
+    type PartTwo = Option<usize>;
+
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Operation Order";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    #[rustfmt::skip]
-    fn test_part_one() {
-        assert_eq!(part_one("1 + 2 * 3 + 4 * 5 + 6"), Some(71));
-        assert_eq!(part_one("1 + (2 * 3) + (4 * (5 + 6))"), Some(51));
-        assert_eq!(part_one("2 * 3 + (4 * 5)"), Some(26));
-        assert_eq!(part_one("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Some(437));
-        assert_eq!(part_one("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"), Some(12240));
-        assert_eq!(part_one("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"), Some(13632));
+    fn part_one(input: &str) -> Self::PartOne {
+        part_one(input)
     }
 
-    #[test]
-    #[rustfmt::skip]
-    fn test_part_two() {
-        assert_eq!(part_two("2 * 3 + 4 * 5"), Some(70));
-        assert_eq!(part_two("1 + 2 * 3 + 4 * 5 + 6"), Some(231));
-        assert_eq!(part_two("1 + (2 * 3) + (4 * (5 + 6))"), Some(51));
-        assert_eq!(part_two("2 * 3 + (4 * 5)"), Some(46));
-        assert_eq!(part_two("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Some(1445));
-        assert_eq!(part_two("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"), Some(669060));
-        assert_eq!(part_two("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"), Some(23340));
+    fn part_two(input: &str) -> Self::PartTwo {
+        part_two(input)
     }
 }
@@ -0,0 +1,69 @@
+/*
+ * The solutions of 2020. Every solution lives in its own `dayNN` module and is listed in `DAYS` below.
+ * `INPUTS` embeds the matching input files when the `embed-inputs` feature is enabled.
+ * `cargo scaffold` adds new days to all three lists automatically.
+ */
+use crate::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day13::Day13>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+];
+
+/// Inputs compiled into the binaries with the `embed-inputs` feature.
+#[cfg(feature = "embed-inputs")]
+pub const INPUTS: &[(u8, &str)] = &[
+    (1, include_str!("../../inputs/2020/01.txt")),
+    (2, include_str!("../../inputs/2020/02.txt")),
+    (3, include_str!("../../inputs/2020/03.txt")),
+    (4, include_str!("../../inputs/2020/04.txt")),
+    (5, include_str!("../../inputs/2020/05.txt")),
+    (6, include_str!("../../inputs/2020/06.txt")),
+    (7, include_str!("../../inputs/2020/07.txt")),
+    (8, include_str!("../../inputs/2020/08.txt")),
+    (9, include_str!("../../inputs/2020/09.txt")),
+    (10, include_str!("../../inputs/2020/10.txt")),
+    (11, include_str!("../../inputs/2020/11.txt")),
+    (12, include_str!("../../inputs/2020/12.txt")),
+    (13, include_str!("../../inputs/2020/13.txt")),
+    (14, include_str!("../../inputs/2020/14.txt")),
+    (15, include_str!("../../inputs/2020/15.txt")),
+    (16, include_str!("../../inputs/2020/16.txt")),
+    (17, include_str!("../../inputs/2020/17.txt")),
+    (18, include_str!("../../inputs/2020/18.txt")),
+];
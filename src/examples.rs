@@ -0,0 +1,115 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use std::{env, fs, io, path::PathBuf};
+
+/// An example input of a day, stored in `src/examples/YYYY/NN.txt` or `src/examples/YYYY/NN-<name>.txt`.
+///
+/// The expected answers are declared next to it in `NN.answers` or `NN-<name>.answers`, using
+/// the same `<part>: <answer>` lines as `src/answers`. Parts without an answer are not checked.
+#[derive(Debug)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: Option<String>,
+    pub path: PathBuf,
+    pub answers: Answers,
+}
+
+impl Example {
+    /// The file stem of the example, e.g. `10` or `10-larger`.
+    pub fn label(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{:02}-{name}", self.day),
+            None => format!("{:02}", self.day),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        fs::read_to_string(&self.path)
+    }
+}
+
+pub fn dir(year: u16) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("src")
+        .join("examples")
+        .join(year.to_string())
+}
+
+/// Finds all examples of `day`, the unnamed `NN.txt` first and the named ones sorted by name.
+pub fn find(year: u16, day: u8) -> io::Result<Vec<Example>> {
+    let mut names = vec![];
+
+    let entries = match fs::read_dir(dir(year)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    for entry in entries {
+        let file_name = entry?.file_name();
+        let Some(stem) = file_name.to_str().and_then(|f| f.strip_suffix(".txt")) else {
+            continue;
+        };
+
+        if let Some(name) = parse_stem(stem, day) {
+            names.push(name);
+        }
+    }
+
+    names.sort();
+    names
+        .into_iter()
+        .map(|name| load(year, day, name))
+        .collect()
+}
+
+/// Returns `Some(None)` for `NN`, `Some(Some(name))` for `NN-<name>`, and `None` for stems of
+/// other days.
+fn parse_stem(stem: &str, day: u8) -> Option<Option<String>> {
+    let rest = stem.strip_prefix(&format!("{day:02}"))?;
+
+    if rest.is_empty() {
+        return Some(None);
+    }
+
+    match rest.strip_prefix('-') {
+        Some(name) if !name.is_empty() => Some(Some(name.to_string())),
+        _ => None,
+    }
+}
+
+fn load(year: u16, day: u8, name: Option<String>) -> io::Result<Example> {
+    let stem = match &name {
+        Some(name) => format!("{day:02}-{name}"),
+        None => format!("{day:02}"),
+    };
+
+    let answers = Answers::read(&dir(year).join(format!("{stem}.answers")))?;
+
+    Ok(Example {
+        year,
+        day,
+        name,
+        path: dir(year).join(format!("{stem}.txt")),
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stem() {
+        assert_eq!(parse_stem("10", 10), Some(None));
+        assert_eq!(parse_stem("10-larger", 10), Some(Some("larger".into())));
+        assert_eq!(parse_stem("10-", 10), None);
+        assert_eq!(parse_stem("100", 10), None);
+        assert_eq!(parse_stem("01", 10), None);
+    }
+}
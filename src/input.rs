@@ -0,0 +1,152 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::file_path;
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+/// Where the puzzle input of a day comes from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InputSource {
+    /// `src/inputs/YYYY/NN.txt`, or the input compiled into the binary with the `embed-inputs` feature.
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads `--input <path>` from the command line, where `-` selects stdin.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let input: Option<PathBuf> = args.opt_value_from_str("--input")?;
+
+        Ok(match input {
+            None => InputSource::Default,
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::Path(path),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputError {
+    /// The default input file of `day` does not exist.
+    Missing {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    /// An explicitly given input file does not exist.
+    NotFound(PathBuf),
+    Unreadable {
+        path: PathBuf,
+        reason: String,
+    },
+    Stdin(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => write!(
+                f,
+                "could not open input file \"{}\". Try running `cargo download {day} --year {year}` first.",
+                path.display()
+            ),
+            InputError::NotFound(path) => {
+                write!(f, "could not open input file \"{}\".", path.display())
+            }
+            InputError::Unreadable { path, reason } => {
+                write!(
+                    f,
+                    "could not read input file \"{}\": {reason}",
+                    path.display()
+                )
+            }
+            InputError::Stdin(reason) => write!(f, "could not read input from stdin: {reason}"),
+        }
+    }
+}
+
+/// Reads `path`, which is the default input of `puzzle` (year and day) if given.
+fn read_path(path: PathBuf, puzzle: Option<(u16, u8)>) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|e| match (e.kind(), puzzle) {
+        (io::ErrorKind::NotFound, Some((year, day))) => InputError::Missing { year, day, path },
+        (io::ErrorKind::NotFound, None) => InputError::NotFound(path),
+        _ => InputError::Unreadable {
+            path,
+            reason: e.to_string(),
+        },
+    })
+}
+
+#[cfg(feature = "embed-inputs")]
+fn read_default(year: u16, day: u8) -> Result<String, InputError> {
+    let embedded = crate::days::INPUTS
+        .iter()
+        .filter(|(input_year, _)| *input_year == year)
+        .flat_map(|(_, inputs)| inputs.iter())
+        .find(|(input_day, _)| *input_day == day);
+
+    match embedded {
+        Some((_, input)) => Ok(input.to_string()),
+        None => read_path(file_path("inputs", year, day), Some((year, day))),
+    }
+}
+
+#[cfg(not(feature = "embed-inputs"))]
+fn read_default(year: u16, day: u8) -> Result<String, InputError> {
+    read_path(file_path("inputs", year, day), Some((year, day)))
+}
+
+pub fn load(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => read_default(year, day),
+        InputSource::Path(path) => read_path(path.clone(), None),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| InputError::Stdin(e.to_string()))?;
+            Ok(input)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_path() {
+        let path = file_path("examples", 2020, 1);
+        assert_eq!(
+            load(2020, 1, &InputSource::Path(path.clone())),
+            Ok(fs::read_to_string(path).unwrap())
+        );
+
+        assert_eq!(
+            load(2020, 1, &InputSource::Path("does/not/exist.txt".into())),
+            Err(InputError::NotFound("does/not/exist.txt".into()))
+        );
+    }
+
+    #[test]
+    fn test_missing_input_message() {
+        let error = InputError::Missing {
+            year: 2020,
+            day: 7,
+            path: "src/inputs/2020/07.txt".into(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "could not open input file \"src/inputs/2020/07.txt\". Try running `cargo download 7 --year 2020` first."
+        );
+    }
+}
@@ -4,231 +4,398 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Display;
 use std::fs;
-
+use std::path::PathBuf;
+
+pub mod alloc;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod client;
+pub mod dashboard;
+pub mod days;
+pub mod examples;
 pub mod helpers;
+pub mod input;
+pub mod parse;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod submit;
+pub mod template;
+pub mod watch;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+/// A puzzle solution for a single day.
+///
+/// Implementors are registered in [`days::DAYS`] so that the runners can enumerate and call
+/// every solution in-process.
+pub trait Solution {
+    type PartOne: PartResult;
+    type PartTwo: PartResult;
+
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    fn part_one(input: &str) -> Self::PartOne;
+    fn part_two(input: &str) -> Self::PartTwo;
+}
+
+/// What a part of a [`Solution`] can return: a number or string, `Option<T>` when it may have no
+/// answer, `Result<T, E>` when it can fail, e.g. with a [`parse::ParseError`], or
+/// `Result<Option<T>, E>` for both.
+pub trait PartResult {
+    /// `Ok(None)` if there is no answer, `Err` with the error message if the part failed.
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
+
+macro_rules! impl_part_result {
+    ($($answer:ty),*) => {
+        $(
+            impl PartResult for $answer {
+                fn into_answer(self) -> Result<Option<String>, String> {
+                    Ok(Some(self.to_string()))
                 }
             }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+        )*
+    };
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+impl_part_result!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+impl<T: Display> PartResult for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+impl<T: PartResult, E: Display> PartResult for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        self.map_err(|error| error.to_string())?.into_answer()
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// A type-erased part of a [`Solution`].
+pub type Solver = fn(&str) -> Result<Option<String>, String>;
+
+/// A type-erased [`Solution`], as stored in the registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub part_one: Solver,
+    pub part_two: Solver,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            part_one: erased_part_one::<S>,
+            part_two: erased_part_two::<S>,
         }
-    })
+    }
+
+    pub fn parts(&self) -> [(u8, Solver); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+fn erased_part_one<S: Solution>(input: &str) -> Result<Option<String>, String> {
+    S::part_one(input).into_answer()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
-    }
+fn erased_part_two<S: Solution>(input: &str) -> Result<Option<String>, String> {
+    S::part_two(input).into_answer()
+}
+
+/// `src/<folder>/<year>/<day>.txt`, e.g. `src/inputs/2020/07.txt`.
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(format!("{day:02}.txt"))
+}
+
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    let path = file_path(folder, year, day);
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not open file \"{}\": {e}", path.display()))
 }
 
 pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
+    use crate::{
+        client::{self, Client, ClientError},
+        puzzle,
+        submit::Response,
     };
+    use std::{error::Error, fmt::Display, fs, io, path::Path, process, time::Duration};
 
+    #[derive(Debug)]
     pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
+        /// No session cookie is configured.
+        MissingSession,
+        /// The website did not accept the session cookie, most likely because it expired.
+        InvalidSession {
+            status: u16,
+        },
+        /// The puzzle is not available yet, with the time until it unlocks if known.
+        NotAvailable {
+            unlocks_in: Option<Duration>,
+        },
+        /// The website answered with another error status.
+        Http {
+            status: u16,
+            body: String,
+        },
+        /// The website could not be reached.
+        Client(ClientError),
+        Io {
+            path: String,
+            source: io::Error,
+        },
+    }
+
+    impl AocCliError {
+        /// The exit status of `cargo download`, `cargo read` and `cargo submit` for this error:
+        /// `2` no session cookie, `3` expired session cookie, `4` puzzle not available yet,
+        /// `5` other request failures and `6` file system errors.
+        pub fn exit_code(&self) -> i32 {
+            match self {
+                AocCliError::MissingSession => 2,
+                AocCliError::InvalidSession { .. } => 3,
+                AocCliError::NotAvailable { .. } => 4,
+                AocCliError::Http { .. } | AocCliError::Client(_) => 5,
+                AocCliError::Io { .. } => 6,
+            }
+        }
+
+        /// Prints the error and its causes to stderr and exits with [`Self::exit_code`].
+        pub fn exit(&self, context: &str) -> ! {
+            eprintln!("{context}: {self}");
+
+            let mut source = Error::source(self);
+            while let Some(cause) = source {
+                eprintln!("  caused by: {cause}");
+                source = cause.source();
+            }
+
+            process::exit(self.exit_code());
+        }
     }
 
     impl Display for AocCliError {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
+                AocCliError::MissingSession => write!(f, "{}", ClientError::MissingSession),
+                AocCliError::InvalidSession { status } => write!(
+                    f,
+                    "the session cookie was rejected (status {status}), it has probably expired. Replace it with a fresh one."
+                ),
+                AocCliError::NotAvailable {
+                    unlocks_in: Some(wait),
+                } => {
+                    let seconds = wait.as_secs();
+                    write!(
+                        f,
+                        "the puzzle is not unlocked yet, it unlocks in {}h {:02}m {:02}s.",
+                        seconds / 3600,
+                        seconds / 60 % 60,
+                        seconds % 60
+                    )
+                }
+                AocCliError::NotAvailable { unlocks_in: None } => {
+                    write!(f, "the puzzle is not available yet.")
+                }
+                AocCliError::Http { status, body } => {
+                    write!(f, "the website answered with status {status}")?;
+                    match body.lines().map(str::trim).find(|line| !line.is_empty()) {
+                        Some(line) => write!(f, ": {}", line.chars().take(200).collect::<String>()),
+                        None => write!(f, "."),
+                    }
+                }
+                AocCliError::Client(_) => write!(f, "could not reach the website."),
+                AocCliError::Io { path, .. } => write!(f, "could not write \"{path}\"."),
+            }
+        }
+    }
+
+    impl Error for AocCliError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                AocCliError::Client(e) => Some(e),
+                AocCliError::Io { source, .. } => Some(source),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<ClientError> for AocCliError {
+        fn from(e: ClientError) -> Self {
+            match e {
+                ClientError::MissingSession => AocCliError::MissingSession,
+                ClientError::Status { code: 404, .. } => {
+                    AocCliError::NotAvailable { unlocks_in: None }
                 }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
+                ClientError::Status {
+                    code: status @ (400 | 401 | 403),
+                    ..
+                } => AocCliError::InvalidSession { status },
+                ClientError::Status { code, body } => AocCliError::Http { status: code, body },
+                e @ ClientError::Transport(_) => AocCliError::Client(e),
             }
         }
     }
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+    fn io_error(path: &str) -> impl FnOnce(io::Error) -> AocCliError + '_ {
+        move |source| AocCliError::Io {
+            path: path.to_string(),
+            source,
+        }
     }
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+    /// Prints the puzzle description of `day` from `src/puzzles`. It is only fetched if it is not
+    /// stored there yet, or if `refresh` is set.
+    pub fn read(day: u8, year: u16, refresh: bool) -> Result<(), AocCliError> {
+        let puzzle_path = get_puzzle_path(day, year);
+
+        let markdown = match fs::read_to_string(&puzzle_path) {
+            Ok(markdown) if !refresh => markdown,
+            _ => {
+                let markdown = puzzle::to_markdown(&connect(day, year)?.puzzle(year, day)?);
+                fs::create_dir_all(format!("src/puzzles/{year}"))
+                    .map_err(io_error(&puzzle_path))?;
+                fs::write(&puzzle_path, &markdown).map_err(io_error(&puzzle_path))?;
+                markdown
+            }
+        };
+
+        print!("{}", puzzle::render(&markdown));
+        Ok(())
     }
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+    /// Downloads the input and puzzle description of `day`. Files that already exist (inputs that
+    /// are not empty) are skipped unless `force` is set.
+    pub fn download(day: u8, year: u16, force: bool) -> Result<(), AocCliError> {
+        let input_path = get_input_path(day, year);
+        let puzzle_path = get_puzzle_path(day, year);
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+        let fetch_input = force || fs::metadata(&input_path).map_or(true, |file| file.len() == 0);
+        let fetch_puzzle = force || !Path::new(&puzzle_path).exists();
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
+        if !fetch_input && !fetch_puzzle {
+            println!("🎄 Day {day} of {year} is already downloaded. Append `--force` to download it again.");
+            return Ok(());
+        }
 
-        let output = call_aoc_cli(&args)?;
+        let client = connect(day, year)?;
 
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
+        if fetch_puzzle {
+            fs::create_dir_all(format!("src/puzzles/{year}")).map_err(io_error(&puzzle_path))?;
+            println!("Saving puzzle description to \"{puzzle_path}\"...");
+            let puzzle = puzzle::to_markdown(&client.puzzle(year, day)?);
+            fs::write(&puzzle_path, puzzle).map_err(io_error(&puzzle_path))?;
         } else {
-            Err(AocCliError::BadExitStatus(output))
+            println!("Skipping puzzle description, \"{puzzle_path}\" already exists.");
         }
-    }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
-    }
+        if fetch_input {
+            fs::create_dir_all(format!("src/inputs/{year}")).map_err(io_error(&input_path))?;
+            println!("Saving puzzle input to \"{input_path}\"...");
+            let input = client.input(year, day)?;
+            fs::write(&input_path, input).map_err(io_error(&input_path))?;
+        } else {
+            println!("Skipping puzzle input, \"{input_path}\" already exists.");
+        }
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+        println!("---");
+        if fetch_input {
+            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        }
+        if fetch_puzzle {
+            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        }
+        Ok(())
     }
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+    /// Submits `answer` for `part` of `day`.
+    pub fn submit(day: u8, year: u16, part: u8, answer: &str) -> Result<Response, AocCliError> {
+        let html = connect(day, year)?.submit(year, day, part, answer)?;
+        Ok(Response::parse(&html))
+    }
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
+    /// A client for `day`, which has to be unlocked already.
+    fn connect(day: u8, year: u16) -> Result<Client, AocCliError> {
+        if let Some(wait) = client::time_until_unlock(year, day) {
+            return Err(AocCliError::NotAvailable {
+                unlocks_in: Some(wait),
+            });
         }
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+        Ok(Client::from_env()?)
+    }
 
-        cmd_args
+    fn get_input_path(day: u8, year: u16) -> String {
+        let day_padded = format!("{day:02}");
+        format!("src/inputs/{year}/{day_padded}.txt")
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
+    fn get_puzzle_path(day: u8, year: u16) -> String {
+        let day_padded = format!("{day:02}");
+        format!("src/puzzles/{year}/{day_padded}.md")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_from_client_error() {
+            let status = |code: u16, body: &str| {
+                AocCliError::from(ClientError::Status {
+                    code,
+                    body: body.into(),
+                })
+            };
+
+            assert_eq!(
+                AocCliError::from(ClientError::MissingSession).exit_code(),
+                2
+            );
+            assert_eq!(status(400, "Please log in").exit_code(), 3);
+            assert_eq!(
+                status(404, "Please don't repeatedly request").exit_code(),
+                4
+            );
+            assert_eq!(
+                status(502, "\n  <html>Bad gateway</html>\n").to_string(),
+                "the website answered with status 502: <html>Bad gateway</html>"
+            );
         }
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+        #[test]
+        fn test_source() {
+            let error = io_error("src/inputs/2020/01.txt")(io::ErrorKind::PermissionDenied.into());
+
+            assert_eq!(error.exit_code(), 6);
+            assert_eq!(
+                error.to_string(),
+                "could not write \"src/inputs/2020/01.txt\"."
+            );
+            assert!(error
+                .source()
+                .is_some_and(|source| source.to_string() == "permission denied"));
+        }
     }
 }
@@ -2,44 +2,201 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::{
+    alloc,
+    answers::{self, Answers},
+    baseline::{self, BaselineConfig},
+    bench::BenchConfig,
+    days,
+    runner::{self, DayReport},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    process, thread,
+    time::{Duration, Instant},
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+struct Args {
+    year: Option<u16>,
+    json: bool,
+    check: bool,
+    confirm: bool,
+    parallel: Option<usize>,
+    bench: Option<BenchConfig>,
+    baseline: BaselineConfig,
+    timeout: Option<Duration>,
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        json: args.contains("--json"),
+        check: args.contains("--check"),
+        confirm: args.contains("--confirm"),
+        parallel: parse_parallel(&mut args)?,
+        bench: BenchConfig::from_args(&mut args)?,
+        baseline: BaselineConfig::from_args(&mut args)?,
+        timeout: runner::Options::timeout_from_args(&mut args)?,
+    })
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+/// `--parallel` runs the days on one thread per core, `--threads <n>` overrides the count.
+fn parse_parallel(args: &mut pico_args::Arguments) -> Result<Option<usize>, pico_args::Error> {
+    let parallel = args.contains("--parallel");
+    let threads: Option<usize> = args.opt_value_from_str("--threads")?;
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+    Ok(match (parallel, threads) {
+        (_, Some(threads)) => Some(threads.max(1)),
+        (true, None) => Some(thread::available_parallelism().map_or(1, |n| n.get())),
+        (false, None) => None,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let options = runner::Options {
+        bench: args.bench,
+        timeout: args.timeout,
+        ..runner::Options::default()
+    };
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+    // without `--year`, every registered year runs.
+    let years: Vec<u16> = match args.year {
+        Some(year) if days::year(year).is_none() => {
+            eprintln!("No days of {year} are registered.");
+            process::exit(1);
+        }
+        Some(year) => vec![year],
+        None => days::YEARS.iter().map(|year| year.year).collect(),
+    };
+    let days: Vec<(u16, u8)> = years
+        .iter()
+        .flat_map(|&year| (1..=25).map(move |day| (year, day)))
+        .collect();
+    let mut reports = Vec::with_capacity(days.len());
+    let timer = Instant::now();
 
+    let mut on_report = |report: DayReport| {
+        if args.json {
+            report.json_lines().for_each(|line| println!("{line}"));
+        } else {
+            println!("---------------");
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "{ANSI_BOLD}| {} Day {:02} |{ANSI_RESET}",
+                report.year, report.day
             );
+            println!("---------------");
+            report.print();
+        }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
+        reports.push(report);
+    };
+
+    match args.parallel {
+        Some(threads) => runner::run_days_parallel(&days, &options, threads, on_report),
+        None => days
+            .iter()
+            .for_each(|&(year, day)| on_report(runner::run_day(year, day, &options))),
+    }
+
+    let wall_nanos = timer.elapsed().as_nanos();
+
+    let total_nanos: u128 = reports
+        .iter()
+        .map(|report| report.elapsed().as_nanos())
         .sum();
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    if args.json {
+        match args.parallel {
+            Some(_) => println!("{{\"total_nanos\":{total_nanos},\"wall_nanos\":{wall_nanos}}}"),
+            None => println!("{{\"total_nanos\":{total_nanos}}}"),
+        }
+    } else {
+        if alloc::enabled() {
+            print_memory_summary(&reports);
+        }
+
+        println!(
+            "{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            total_nanos as f64 / 1_000_000_f64
+        );
+
+        if args.parallel.is_some() {
+            println!(
+                "{ANSI_BOLD}Wall clock:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+                wall_nanos as f64 / 1_000_000_f64
+            );
+        }
+    }
+
+    if args.confirm {
+        reports.iter().for_each(confirm_answers);
+    }
+
+    let mismatches = reports
+        .iter()
+        .filter(|report| report.has_mismatch())
+        .count();
+
+    if args.check && mismatches > 0 {
+        eprintln!("❌ {mismatches} day(s) produced answers that do not match \"src/answers\".");
+    }
+
+    if !baseline::report(&args.baseline, &reports) || (args.check && mismatches > 0) {
+        process::exit(1);
+    }
+}
+
+/// Prints the timing and heap usage of every solved part, see the `count-allocations` feature.
+fn print_memory_summary(reports: &[DayReport]) {
+    println!("-----------");
+    println!("{ANSI_BOLD}| Summary |{ANSI_RESET}");
+    println!("-----------");
+    println!(
+        "{:<8} {:<6} {:>12} {:>12} {:>12}",
+        "Day", "Part", "Time", "Peak heap", "Allocs"
+    );
+
+    for report in reports {
+        for part in &report.parts {
+            let (Some(elapsed), Some(memory)) = (part.outcome.elapsed(), part.outcome.memory())
+            else {
+                continue;
+            };
+
+            println!(
+                "{:<8} {:<6} {:>12} {:>12} {:>12}",
+                format!("{}/{:02}", report.year, report.day),
+                part.part,
+                format!("{elapsed:.2?}"),
+                alloc::format_bytes(memory.peak_bytes),
+                memory.allocations
+            );
+        }
+    }
+}
+
+fn confirm_answers(report: &DayReport) {
+    match answers::confirm(report) {
+        Ok(parts) => {
+            for part in parts {
+                eprintln!(
+                    "Recorded answer for day {:02} part {part} in \"{}\".",
+                    report.day,
+                    Answers::path(report.year, report.day).display()
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to record answers for day {:02}: {e}", report.day);
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,89 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+
+/// Malformed puzzle input, pointing at the offending line and, if known, column (both 1-based).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Points the error at `token`, which has to be a subslice of `text`, the content of `line`.
+    pub fn at(line: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        Self {
+            column: column(text, token),
+            ..Self::new(line, message)
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.column {
+            Some(column) => write!(
+                f,
+                "bad input at line {}, column {column}: {}",
+                self.line, self.message
+            ),
+            None => write!(f, "bad input at line {}: {}", self.line, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Iterates the lines of `input` together with their 1-based line numbers.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+/// The 1-based column at which `token` starts in `text`, if it is a subslice of it.
+fn column(text: &str, token: &str) -> Option<usize> {
+    let start = text.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).checked_sub(start)?;
+
+    (offset + token.len() <= text.len()).then(|| text[..offset].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let line = "1-x b: cdefg";
+        let (_, second) = line[..3].split_once('-').unwrap();
+
+        assert_eq!(
+            ParseError::at(2, line, second, "expected a number").to_string(),
+            "bad input at line 2, column 3: expected a number"
+        );
+        assert_eq!(
+            ParseError::new(37, "unknown action").to_string(),
+            "bad input at line 37: unknown action"
+        );
+    }
+
+    #[test]
+    fn test_column_of_foreign_token() {
+        let text = "abc";
+        assert_eq!(column(text, &String::from("b")), None);
+        assert_eq!(column(text, &text[3..]), Some(4));
+    }
+}
@@ -0,0 +1,439 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Converts puzzle pages of the website to the markdown stored in `src/puzzles`, and renders that
+//! markdown for the terminal.
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+
+/// The markdown of every `<article>` of a puzzle page, i.e. the description of both parts.
+pub fn to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let Some(open_end) = article.find('>') else {
+            break;
+        };
+        let end = article.find("</article>").unwrap_or(article.len());
+
+        articles.push(convert(&article[open_end + 1..end]));
+        rest = &article[end..];
+    }
+
+    articles.join("\n")
+}
+
+fn convert(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, rest, in_pre);
+            break;
+        };
+
+        push_text(&mut out, &rest[..tag_start], in_pre);
+
+        let tag_end = rest[tag_start..]
+            .find('>')
+            .map_or(rest.len(), |end| tag_start + end + 1);
+        let tag = &rest[tag_start..tag_end];
+        rest = &rest[tag_end..];
+
+        let closing = tag.starts_with("</");
+        let name: String = tag
+            .trim_start_matches(['<', '/'])
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect();
+
+        match (name.as_str(), closing) {
+            ("h2", false) => {
+                start_block(&mut out);
+                out.push_str("## ");
+            }
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("p" | "ul", false) => start_block(&mut out),
+            ("li", false) => {
+                start_line(&mut out);
+                out.push_str("- ");
+            }
+            ("ul", true) => out.push('\n'),
+            ("pre", false) => {
+                start_block(&mut out);
+                out.push_str("```\n");
+                in_pre = true;
+            }
+            ("pre", true) => {
+                start_line(&mut out);
+                out.push_str("```\n\n");
+                in_pre = false;
+            }
+            // code blocks are rendered verbatim.
+            (_, _) if in_pre => {}
+            ("code", _) => out.push('`'),
+            ("em", _) => out.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    tidy(&out)
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    let text = decode_entities(text);
+
+    if in_pre {
+        out.push_str(&text);
+    } else {
+        out.push_str(&text.replace('\n', " "));
+    }
+}
+
+fn start_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn start_block(out: &mut String) {
+    start_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+/// Strips trailing whitespace from lines and collapses runs of blank lines.
+fn tidy(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut blank = true;
+
+    for line in markdown.lines().map(str::trim_end) {
+        if line.trim().is_empty() {
+            if !blank {
+                out.push('\n');
+            }
+            blank = true;
+            continue;
+        }
+
+        out.push_str(line);
+        out.push('\n');
+        blank = false;
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| Some((decode_entity(&rest[1..end])?, end)));
+
+        match entity {
+            Some((decoded, end)) => {
+                out.push(decoded);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "amp" => Some('&'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        _ => {
+            let code = match name.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => name.strip_prefix('#')?.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// The title of the puzzle, e.g. `Report Repair` for `## --- Day 1: Report Repair ---`.
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("## --- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim_end_matches(" ---").trim().to_string())
+}
+
+/// The first code block of the description, which is the example input of most puzzles.
+pub fn example(markdown: &str) -> Option<String> {
+    let mut lines = markdown.lines().skip_while(|line| !line.starts_with("```"));
+    lines.next()?;
+
+    let example: Vec<&str> = lines.take_while(|line| !line.starts_with("```")).collect();
+    (!example.is_empty()).then(|| example.join("\n") + "\n")
+}
+
+/// The answer of `part` for the example, if the description has it: the last highlighted code
+/// span of the part, e.g. `514579` in ``so the correct answer is `*514579*`.``
+pub fn example_answer(markdown: &str, part: u8) -> Option<String> {
+    let (part_one, part_two) = match markdown.split_once("## --- Part Two ---") {
+        Some((part_one, part_two)) => (part_one, Some(part_two)),
+        None => (markdown, None),
+    };
+
+    let text = match part {
+        1 => part_one,
+        _ => part_two?,
+    };
+
+    let mut answer = None;
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        let highlighted = ["`*", "*`"]
+            .iter()
+            .find(|open| rest[start..].starts_with(*open))
+            .and_then(|open| {
+                let close: String = open.chars().rev().collect();
+                let end = rest[start + 2..].find(&close)?;
+                Some((&rest[start + 2..start + 2 + end], start + 2 + end + 2))
+            });
+
+        match highlighted {
+            Some((code, end)) => {
+                answer = Some(code);
+                rest = &rest[end..];
+            }
+            None => rest = &rest[start + 1..],
+        }
+    }
+
+    // e.g. `*1721 * 299 = 514579*`
+    let answer = answer?.rsplit("= ").next()?.trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
+
+/// Formats puzzle markdown with ANSI escapes: bold headings, highlighted code and emphasis, and
+/// indented code blocks.
+pub fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push_str(&format!("    {ANSI_CODE}{line}{ANSI_RESET}\n"));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            out.push_str(&render_inline(heading, &[ANSI_BOLD]));
+            out.push('\n');
+        } else if let Some(item) = line.strip_prefix("- ") {
+            out.push_str("  • ");
+            out.push_str(&render_inline(item, &[]));
+            out.push('\n');
+        } else {
+            out.push_str(&render_inline(line, &[]));
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+/// Renders `code`, `*emphasis*` and `[links](href)` on top of the `base` styles.
+fn render_inline(text: &str, base: &[&'static str]) -> String {
+    let mut out = base.concat();
+    let mut styles = base.to_vec();
+    let mut rest = text;
+
+    let restyle = |out: &mut String, styles: &[&str]| {
+        out.push_str(ANSI_RESET);
+        out.push_str(&styles.concat());
+    };
+
+    while let Some(c) = rest.chars().next() {
+        let in_code = styles.contains(&ANSI_CODE);
+
+        if c == '`' || (c == '*' && !in_code) {
+            let style = if c == '`' { ANSI_CODE } else { ANSI_ITALIC };
+            match styles.iter().position(|&active| active == style) {
+                Some(index) => {
+                    styles.remove(index);
+                }
+                None => styles.push(style),
+            }
+            restyle(&mut out, &styles);
+            rest = &rest[1..];
+            continue;
+        }
+
+        if let Some((label, len)) = (c == '[' && !in_code).then(|| link(rest)).flatten() {
+            let linked = [styles.as_slice(), &[ANSI_UNDERLINE]].concat();
+            out.push_str(&render_inline(label, &linked));
+            out.push_str(&styles.concat());
+            rest = &rest[len..];
+            continue;
+        }
+
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !styles.is_empty() {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// The label of a `[label](href)` link at the start of `text` and the length of the link.
+fn link(text: &str) -> Option<(&str, usize)> {
+    if !text.starts_with('[') {
+        return None;
+    }
+
+    let label_end = text.find("](")?;
+    let href_end = text[label_end..].find(')')? + label_end;
+    Some((&text['['.len_utf8()..label_end], href_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_markdown() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Report Repair ---</h2><p>Find the <em>two entries</em> that sum to <code>2020</code>; see <a href="/2020/about">about</a>.</p>
+<ul>
+<li><code>1721 &amp; 299</code></li>
+<li>&lt;done&gt;</li>
+</ul>
+<pre><code>1721
+<em>979</em>
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>514579</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Three&#39;s company.</p></article>
+</main></body></html>"#;
+
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Report Repair ---
+
+Find the *two entries* that sum to `2020`; see [about](/2020/about).
+
+- `1721 & 299`
+- <done>
+
+```
+1721
+979
+```
+
+## --- Part Two ---
+
+Three's company.
+"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let markdown = "## --- Day 1: Report Repair ---
+
+For example:
+
+```
+1721
+979
+```
+
+Multiplying them produces `1721 * 299 = 514579`, so the answer is `*514579*`.
+
+```
+other
+```
+
+## --- Part Two ---
+
+Their product is *`241861950`*.
+";
+
+        assert_eq!(title(markdown), Some("Report Repair".into()));
+        assert_eq!(example(markdown), Some("1721\n979\n".into()));
+        assert_eq!(example_answer(markdown, 1), Some("514579".into()));
+        assert_eq!(example_answer(markdown, 2), Some("241861950".into()));
+
+        let part_one = markdown.split("## --- Part Two").next().unwrap();
+        assert_eq!(example_answer(part_one, 2), None);
+        assert_eq!(
+            example_answer("The answer is `*1 + 2 = 3*`.", 1),
+            Some("3".into())
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render("Plain text.\n\n"), "Plain text.\n\n");
+        assert_eq!(
+            render("## Day `1`\n- *two* [entries](/x)\n```\n1721\n```\n"),
+            format!(
+                "{ANSI_BOLD}Day {ANSI_RESET}{ANSI_BOLD}{ANSI_CODE}1{ANSI_RESET}{ANSI_BOLD}{ANSI_RESET}\n  \
+                 • {ANSI_RESET}{ANSI_ITALIC}two{ANSI_RESET} {ANSI_UNDERLINE}entries{ANSI_RESET}\n    \
+                 {ANSI_CODE}1721{ANSI_RESET}\n"
+            )
+        );
+        assert_eq!(
+            render("é [x](y)\n"),
+            format!("é {ANSI_UNDERLINE}x{ANSI_RESET}\n")
+        );
+        assert_eq!(
+            render("snow ❄ see [here](/x)\n"),
+            format!("snow ❄ see {ANSI_UNDERLINE}here{ANSI_RESET}\n")
+        );
+        assert_eq!(render("a ](b)\n"), "a ](b)\n");
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::DayReport;
+use std::{fs, io, path::Path, time::Duration};
+
+pub const TABLE_START: &str = "<!--- benchmarking table --->";
+pub const TABLE_END: &str = "<!--- benchmarking table end --->";
+
+#[derive(Debug)]
+pub enum ReadmeError {
+    IoError(io::Error),
+    /// `README.md` does not contain both markers, in order.
+    MarkersNotFound,
+}
+
+impl std::fmt::Display for ReadmeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadmeError::IoError(e) => write!(f, "could not access README: {e}"),
+            ReadmeError::MarkersNotFound => write!(
+                f,
+                "could not find \"{TABLE_START}\" followed by \"{TABLE_END}\" in README."
+            ),
+        }
+    }
+}
+
+fn format_cell(elapsed: Option<Duration>) -> String {
+    elapsed.map_or_else(String::new, |elapsed| format!("`{elapsed:.2?}`"))
+}
+
+/// Renders one row per report. Parts without an answer are left blank.
+pub fn render_table(reports: &[DayReport]) -> String {
+    let mut table = String::from("## Benchmarks\n\n");
+    table.push_str("| Day | Part 1 | Part 2 | Total |\n");
+    table.push_str("| :---: | :---: | :---: | :---: |\n");
+
+    for report in reports {
+        let timings: Vec<Option<Duration>> = report
+            .parts
+            .iter()
+            .map(|part| part.outcome.elapsed())
+            .collect();
+        let total = timings
+            .iter()
+            .any(Option::is_some)
+            .then(|| report.elapsed());
+
+        table.push_str(&format!(
+            "| [Day {}](./src/days/y{}/day{:02}.rs) | {} | {} | {} |\n",
+            report.day,
+            report.year,
+            report.day,
+            format_cell(timings.first().copied().flatten()),
+            format_cell(timings.get(1).copied().flatten()),
+            format_cell(total),
+        ));
+    }
+
+    let total: Duration = reports.iter().map(DayReport::elapsed).sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+
+    table
+}
+
+/// Replaces everything between the table markers in `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, ReadmeError> {
+    let start = readme
+        .find(TABLE_START)
+        .ok_or(ReadmeError::MarkersNotFound)?
+        + TABLE_START.len();
+    let end = readme[start..]
+        .find(TABLE_END)
+        .ok_or(ReadmeError::MarkersNotFound)?
+        + start;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+pub fn update(path: &Path, reports: &[DayReport]) -> Result<(), ReadmeError> {
+    let readme = fs::read_to_string(path).map_err(ReadmeError::IoError)?;
+    let readme = replace_table(&readme, &render_table(reports))?;
+    fs::write(path, readme).map_err(ReadmeError::IoError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{Outcome, PartReport};
+
+    fn part(part: u8, micros: Option<u64>) -> PartReport {
+        PartReport {
+            part,
+            outcome: match micros {
+                Some(micros) => Outcome::Solved {
+                    answer: String::new(),
+                    elapsed: Duration::from_micros(micros),
+                    stats: None,
+                    memory: None,
+                },
+                None => Outcome::Unsolved,
+            },
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let reports = [
+            DayReport {
+                year: 2020,
+                day: 1,
+                parts: vec![part(1, Some(50)), part(2, Some(1500))],
+            },
+            DayReport {
+                year: 2020,
+                day: 2,
+                parts: vec![part(1, None), part(2, None)],
+            },
+        ];
+
+        assert_eq!(
+            render_table(&reports),
+            "## Benchmarks
+
+| Day | Part 1 | Part 2 | Total |
+| :---: | :---: | :---: | :---: |
+| [Day 1](./src/days/y2020/day01.rs) | `50.00µs` | `1.50ms` | `1.55ms` |
+| [Day 2](./src/days/y2020/day02.rs) |  |  |  |
+
+**Total: 1.55ms**
+"
+        );
+    }
+
+    #[test]
+    fn test_replace_table() {
+        let readme = format!("# Title\n{TABLE_START}\nold\n{TABLE_END}\n\nrest\n");
+
+        assert_eq!(
+            replace_table(&readme, "new\n").unwrap(),
+            format!("# Title\n{TABLE_START}\n\nnew\n\n{TABLE_END}\n\nrest\n")
+        );
+        assert!(matches!(
+            replace_table("# Title\n", "new\n"),
+            Err(ReadmeError::MarkersNotFound)
+        ));
+    }
+}
@@ -0,0 +1,556 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    alloc::{self, Memory},
+    answers::{Answers, Verdict},
+    bench::{self, BenchConfig, Stats},
+    days,
+    input::{self, InputError, InputSource},
+    Solver, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    any::Any,
+    cell::Cell,
+    collections::BTreeMap,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// What happened when a single part was run.
+pub enum Outcome {
+    /// The day is not registered in [`days::YEARS`].
+    NotImplemented,
+    /// The input for the day could not be read.
+    MissingInput(InputError),
+    /// The solution returned `None`.
+    Unsolved,
+    /// The solution returned an error, e.g. a [`crate::parse::ParseError`] on malformed input.
+    Failed(String),
+    /// The solution panicked with the given message.
+    Panicked(String),
+    /// The solution did not return within the timeout. It keeps running on a detached thread.
+    TimedOut(Duration),
+    /// The solution returned an answer. When benchmarked, `elapsed` is the median of `stats`.
+    Solved {
+        answer: String,
+        elapsed: Duration,
+        stats: Option<Stats>,
+        /// The heap usage of the first call, with the `count-allocations` feature.
+        memory: Option<Memory>,
+    },
+}
+
+#[derive(Default)]
+pub struct Options {
+    /// Benchmark every solved part instead of timing a single call.
+    pub bench: Option<BenchConfig>,
+    pub input: InputSource,
+    /// Give up on a part that takes longer than this.
+    pub timeout: Option<Duration>,
+}
+
+impl Options {
+    /// Parses `--timeout-ms <ms>`.
+    pub fn timeout_from_args(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, pico_args::Error> {
+        let timeout_ms: Option<u64> = args.opt_value_from_str("--timeout-ms")?;
+        Ok(timeout_ms.map(Duration::from_millis))
+    }
+}
+
+impl Outcome {
+    pub fn elapsed(&self) -> Option<Duration> {
+        match self {
+            Outcome::Solved { elapsed, .. } => Some(*elapsed),
+            _ => None,
+        }
+    }
+
+    pub fn stats(&self) -> Option<&Stats> {
+        match self {
+            Outcome::Solved { stats, .. } => stats.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn memory(&self) -> Option<&Memory> {
+        match self {
+            Outcome::Solved { memory, .. } => memory.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::NotImplemented => "not_implemented",
+            Outcome::MissingInput(_) => "missing_input",
+            Outcome::Unsolved => "unsolved",
+            Outcome::Failed(_) => "failed",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::TimedOut(_) => "timed_out",
+            Outcome::Solved { .. } => "solved",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::NotImplemented => write!(f, "not implemented."),
+            Outcome::MissingInput(error) => write!(f, "{error}"),
+            Outcome::Unsolved => write!(f, "not solved."),
+            Outcome::Failed(message) => write!(f, "failed: {message}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+            Outcome::TimedOut(timeout) => write!(f, "timed out after {timeout:.2?}."),
+            Outcome::Solved {
+                answer,
+                elapsed,
+                stats,
+                memory,
+            } => {
+                let timing = match stats {
+                    Some(stats) => format!(
+                        "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} runs",
+                        stats.median, stats.mean, stats.std_dev, stats.min, stats.samples
+                    ),
+                    None => format!("elapsed: {elapsed:.2?}"),
+                };
+
+                match memory {
+                    Some(memory) => {
+                        write!(f, "{answer} {ANSI_ITALIC}({timing}, {memory}){ANSI_RESET}")
+                    }
+                    None => write!(f, "{answer} {ANSI_ITALIC}({timing}){ANSI_RESET}"),
+                }
+            }
+        }
+    }
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub outcome: Outcome,
+    /// How the answer compares to `src/answers`, if the part was solved.
+    pub verdict: Option<Verdict>,
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// Total time spent in the parts that produced an answer.
+    pub fn elapsed(&self) -> Duration {
+        self.parts
+            .iter()
+            .filter_map(|part| part.outcome.elapsed())
+            .sum()
+    }
+
+    pub fn print(&self) {
+        for part in &self.parts {
+            println!("🎄 {ANSI_BOLD}Part {}{ANSI_RESET} 🎄", part.part);
+            match &part.verdict {
+                Some(verdict) => println!("{} {verdict}", part.outcome),
+                None => println!("{}", part.outcome),
+            }
+        }
+    }
+
+    /// Whether any part produced an answer that differs from the recorded one.
+    pub fn has_mismatch(&self) -> bool {
+        self.parts
+            .iter()
+            .any(|part| matches!(part.verdict, Some(Verdict::Incorrect { .. })))
+    }
+
+    /// One machine-readable JSON object per part, e.g.
+    /// `{"year":2020,"day":1,"part":1,"status":"solved","answer":"514579","nanos":52061,"stats":null,"peak_bytes":null,"allocations":null,"verdict":"correct","expected":"514579","message":null}`.
+    pub fn json_lines(&self) -> impl Iterator<Item = String> + '_ {
+        self.parts.iter().map(|part| {
+            let null = || String::from("null");
+            let outcome = &part.outcome;
+
+            let answer = match outcome {
+                Outcome::Solved { answer, .. } => json_string(answer),
+                _ => null(),
+            };
+            let nanos = outcome
+                .elapsed()
+                .map_or_else(null, |elapsed| elapsed.as_nanos().to_string());
+            let stats = outcome.stats().map_or_else(null, Stats::to_json);
+            let peak_bytes = outcome
+                .memory()
+                .map_or_else(null, |memory| memory.peak_bytes.to_string());
+            let allocations = outcome
+                .memory()
+                .map_or_else(null, |memory| memory.allocations.to_string());
+            let verdict = part
+                .verdict
+                .as_ref()
+                .map_or_else(null, |verdict| json_string(verdict.status()));
+            let expected = match &part.verdict {
+                Some(Verdict::Correct) => answer.clone(),
+                Some(Verdict::Incorrect { expected }) => json_string(expected),
+                _ => null(),
+            };
+            let message = match outcome {
+                Outcome::MissingInput(error) => json_string(&error.to_string()),
+                Outcome::Panicked(message) | Outcome::Failed(message) => json_string(message),
+                Outcome::TimedOut(_) => json_string(&outcome.to_string()),
+                _ => null(),
+            };
+
+            format!(
+                "{{\"year\":{},\"day\":{},\"part\":{},\"status\":\"{}\",\"answer\":{answer},\"nanos\":{nanos},\"stats\":{stats},\"peak_bytes\":{peak_bytes},\"allocations\":{allocations},\"verdict\":{verdict},\"expected\":{expected},\"message\":{message}}}",
+                self.year,
+                self.day,
+                part.part,
+                outcome.status(),
+            )
+        })
+    }
+}
+
+/// Encodes `value` as a JSON string literal.
+pub fn json_string(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len() + 2);
+    encoded.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            char if char.is_control() => encoded.push_str(&format!("\\u{:04x}", char as u32)),
+            char => encoded.push(char),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is running a solution, whose panics are reported as
+    /// [`Outcome::Panicked`] instead.
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Like [`panic::catch_unwind`], but without printing the panic. Panics on other threads, or
+/// outside of `f`, still go to the previous panic hook.
+fn catch_silently<R>(f: impl FnOnce() -> R) -> thread::Result<R> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+
+    let silenced = SILENCED.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.set(silenced);
+    result
+}
+
+pub fn run_part(solver: Solver, input: &str, options: &Options) -> Outcome {
+    let result = match options.timeout {
+        Some(timeout) => call_with_timeout(solver, input, timeout),
+        None => Ok(call(solver, input)),
+    };
+
+    let (answer, elapsed, memory) = match result {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => return Outcome::Panicked(panic_message(payload)),
+        Err(timeout) => return Outcome::TimedOut(timeout),
+    };
+
+    let answer = match answer {
+        Ok(Some(answer)) => answer,
+        Ok(None) => return Outcome::Unsolved,
+        Err(message) => return Outcome::Failed(message),
+    };
+
+    let stats = match &options.bench {
+        Some(config) => match catch_silently(|| bench::measure(solver, input, config)) {
+            Ok(stats) => Some(stats),
+            Err(payload) => return Outcome::Panicked(panic_message(payload)),
+        },
+        None => None,
+    };
+
+    Outcome::Solved {
+        answer,
+        elapsed: stats.as_ref().map_or(elapsed, |stats| stats.median),
+        stats,
+        memory,
+    }
+}
+
+type CallResult = thread::Result<(Result<Option<String>, String>, Duration, Option<Memory>)>;
+
+fn call(solver: Solver, input: &str) -> CallResult {
+    catch_silently(|| {
+        let timer = Instant::now();
+        let (answer, memory) = alloc::measure(|| solver(input));
+        (answer, timer.elapsed(), memory)
+    })
+}
+
+/// Calls `solver` on a separate thread and stops waiting after `timeout`.
+///
+/// Threads cannot be cancelled, so a part that times out keeps running in the background until
+/// it finishes or the process exits.
+fn call_with_timeout(
+    solver: Solver,
+    input: &str,
+    timeout: Duration,
+) -> Result<CallResult, Duration> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+
+    thread::spawn(move || {
+        let _ = sender.send(call(solver, &input));
+    });
+
+    receiver.recv_timeout(timeout).map_err(|_| timeout)
+}
+
+/// Runs both parts of `day` of `year` against the input selected in `options`.
+pub fn run_day(year: u16, day: u8, options: &Options) -> DayReport {
+    let parts = match days::get(year, day) {
+        None => [1, 2]
+            .map(|part| PartReport {
+                part,
+                outcome: Outcome::NotImplemented,
+                verdict: None,
+            })
+            .into(),
+        Some(solution) => match input::load(year, day, &options.input) {
+            Ok(input) => {
+                let answers = Answers::load(year, day).unwrap_or_default();
+
+                solution
+                    .parts()
+                    .map(|(part, solver)| {
+                        let outcome = run_part(solver, &input, options);
+                        let verdict = match &outcome {
+                            Outcome::Solved { answer, .. } => Some(answers.verify(part, answer)),
+                            _ => None,
+                        };
+
+                        PartReport {
+                            part,
+                            outcome,
+                            verdict,
+                        }
+                    })
+                    .into()
+            }
+            Err(error) => [1, 2]
+                .map(|part| PartReport {
+                    part,
+                    outcome: Outcome::MissingInput(error.clone()),
+                    verdict: None,
+                })
+                .into(),
+        },
+    };
+
+    DayReport { year, day, parts }
+}
+
+/// Runs `days` (year and day pairs) concurrently on `threads` worker threads and passes the
+/// reports to `on_report` in the order of `days`, as soon as all earlier days are done.
+///
+/// Each part is still timed on the worker that runs it.
+pub fn run_days_parallel(
+    days: &[(u16, u8)],
+    options: &Options,
+    threads: usize,
+    mut on_report: impl FnMut(DayReport),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+
+            scope.spawn(move || {
+                while let Some(&(year, day)) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send(run_day(year, day, options)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut order = days.iter();
+        let mut expected = order.next();
+
+        for report in receiver {
+            pending.insert((report.year, report.day), report);
+
+            while let Some(report) = expected.and_then(|puzzle| pending.remove(puzzle)) {
+                on_report(report);
+                expected = order.next();
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_part() {
+        let options = Options::default();
+
+        assert!(matches!(
+            run_part(|input| Ok(Some(input.len().to_string())), "abc", &options),
+            Outcome::Solved { answer, .. } if answer == "3"
+        ));
+        assert!(matches!(
+            run_part(|_| Ok(None), "", &options),
+            Outcome::Unsolved
+        ));
+        assert!(matches!(
+            run_part(|_| panic!("bad input"), "", &options),
+            Outcome::Panicked(message) if message == "bad input"
+        ));
+        // panics after the part are printed again.
+        assert!(!SILENCED.with(Cell::get));
+        assert!(matches!(
+            run_part(|_| Err("bad input at line 1: x".into()), "", &options),
+            Outcome::Failed(message) if message == "bad input at line 1: x"
+        ));
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let report = DayReport {
+            year: 2020,
+            day: 7,
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    outcome: Outcome::Solved {
+                        answer: "42".into(),
+                        elapsed: Duration::from_nanos(1234),
+                        stats: None,
+                        memory: Some(Memory {
+                            peak_bytes: 2048,
+                            allocations: 3,
+                        }),
+                    },
+                    verdict: Some(Verdict::Incorrect {
+                        expected: "24".into(),
+                    }),
+                },
+                PartReport {
+                    part: 2,
+                    outcome: Outcome::Panicked("unexpected \"x\"".into()),
+                    verdict: None,
+                },
+            ],
+        };
+
+        assert_eq!(
+            report.json_lines().collect::<Vec<_>>(),
+            vec![
+                r#"{"year":2020,"day":7,"part":1,"status":"solved","answer":"42","nanos":1234,"stats":null,"peak_bytes":2048,"allocations":3,"verdict":"incorrect","expected":"24","message":null}"#,
+                r#"{"year":2020,"day":7,"part":2,"status":"panicked","answer":null,"nanos":null,"stats":null,"peak_bytes":null,"allocations":null,"verdict":null,"expected":null,"message":"unexpected \"x\""}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_part_bench() {
+        let options = Options {
+            bench: Some(BenchConfig {
+                warmup: Duration::ZERO,
+                iterations: Some(4),
+                ..BenchConfig::default()
+            }),
+            ..Options::default()
+        };
+
+        let outcome = run_part(|_| Ok(Some(String::new())), "", &options);
+        assert_eq!(outcome.stats().map(|stats| stats.samples), Some(4));
+        assert_eq!(outcome.elapsed(), outcome.stats().map(|stats| stats.median));
+    }
+
+    #[test]
+    fn test_run_day_not_implemented() {
+        let report = run_day(2020, 25, &Options::default());
+        assert_eq!(report.parts.len(), 2);
+        assert!(report
+            .parts
+            .iter()
+            .all(|part| matches!(part.outcome, Outcome::NotImplemented)));
+    }
+
+    #[test]
+    fn test_run_days_parallel_in_order() {
+        let days = [(2020, 25), (2020, 24), (2019, 25), (2020, 22)];
+        let mut order = vec![];
+        run_days_parallel(&days, &Options::default(), 3, |report| {
+            order.push((report.year, report.day))
+        });
+        assert_eq!(order, days);
+    }
+
+    #[test]
+    fn test_run_part_timeout() {
+        let options = Options {
+            timeout: Some(Duration::from_millis(50)),
+            ..Options::default()
+        };
+
+        let outcome = run_part(
+            |_| loop {
+                thread::sleep(Duration::from_millis(10));
+            },
+            "",
+            &options,
+        );
+        assert!(
+            matches!(outcome, Outcome::TimedOut(timeout) if timeout == Duration::from_millis(50))
+        );
+
+        let outcome = run_part(|input| Ok(Some(input.to_string())), "42", &options);
+        assert!(matches!(outcome, Outcome::Solved { answer, .. } if answer == "42"));
+
+        let outcome = run_part(|_| panic!("bad input"), "", &options);
+        assert!(matches!(outcome, Outcome::Panicked(message) if message == "bad input"));
+    }
+}
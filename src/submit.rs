@@ -0,0 +1,358 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Answer submission for `cargo submit`: the reply of the website and the ledger of wrong answers.
+use crate::{answers::Answers, puzzle};
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Whether a wrong answer was too high or too low, if the website said so.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The reply of the website to a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Response {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        /// How long to wait before the next submission.
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or part two is not unlocked yet.
+    WrongLevel,
+    /// A reply without any of the known messages, as markdown.
+    Unrecognized(String),
+}
+
+impl Response {
+    /// Parses the HTML page the website answers a submission with.
+    pub fn parse(html: &str) -> Self {
+        let text = puzzle::to_markdown(html);
+
+        if text.contains("That's the right answer") {
+            Response::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+
+            Response::Incorrect {
+                hint,
+                wait: parse_minutes(&text),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Response::RateLimited {
+                wait: parse_time_left(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            Response::Unrecognized(text.trim().to_string())
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Correct => write!(f, "That's the right answer!"),
+            Response::Incorrect { hint, wait } => {
+                write!(f, "That's not the right answer")?;
+                if let Some(hint) = hint {
+                    write!(f, ", your answer is {hint}")?;
+                }
+                write!(f, ".")?;
+                if let Some(wait) = wait {
+                    write!(f, " Wait {wait:?} before trying again.")?;
+                }
+                Ok(())
+            }
+            Response::RateLimited { wait } => {
+                write!(f, "You gave an answer too recently.")?;
+                if let Some(wait) = wait {
+                    write!(f, " Wait {wait:?} before trying again.")?;
+                }
+                Ok(())
+            }
+            Response::WrongLevel => write!(
+                f,
+                "This part can not be submitted. Did you already complete it?"
+            ),
+            Response::Unrecognized(text) => write!(f, "Unexpected response:\n{text}"),
+        }
+    }
+}
+
+/// `You have 1m 34s left to wait.`
+fn parse_time_left(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = text[start..].find(" left to wait")? + start;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// `Please wait one minute before trying again.` or `wait 5 minutes`.
+fn parse_minutes(text: &str) -> Option<Duration> {
+    let start = text.find("wait ")? + "wait ".len();
+    let mut words = text[start..].split_whitespace();
+
+    let minutes = match words.next()? {
+        "one" => 1,
+        "two" => 2,
+        "five" => 5,
+        "ten" => 10,
+        number => number.parse().ok()?,
+    };
+
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// Why the [`Ledger`] rules out an answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rejection {
+    /// The same answer was submitted before and was wrong.
+    Repeated { hint: Option<Hint> },
+    /// A wrong answer that was too high (low) is lower (higher) than or equal to this one.
+    OutOfBounds { known: String, hint: Hint },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::Repeated { hint: None } => {
+                write!(f, "this answer was already submitted and is wrong.")
+            }
+            Rejection::Repeated { hint: Some(hint) } => {
+                write!(f, "this answer was already submitted and is {hint}.")
+            }
+            Rejection::OutOfBounds { known, hint } => {
+                write!(f, "{known} was {hint}, so this answer is {hint} as well.")
+            }
+        }
+    }
+}
+
+/// The wrong answers submitted for a day, stored in `src/answers/YYYY/NN.wrong` as `<part>: <answer>`
+/// lines with the hint if there was one, e.g. `1: 1234 (too high)`.
+#[derive(Debug, Default, PartialEq)]
+pub struct Ledger {
+    entries: Vec<(u8, String, Option<Hint>)>,
+}
+
+impl Ledger {
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        Answers::path(year, day).with_extension("wrong")
+    }
+
+    /// Loads the ledger of `day`, or an empty one if the file does not exist yet.
+    pub fn load(year: u16, day: u8) -> io::Result<Self> {
+        Self::read(&Self::path(year, day))
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> io::Result<()> {
+        let path = Self::path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let entries = contents
+            .lines()
+            .filter_map(|line| {
+                let (part, rest) = line.split_once(':')?;
+                let (answer, hint) = match rest.trim().split_once(" (") {
+                    Some((answer, "too high)")) => (answer, Some(Hint::TooHigh)),
+                    Some((answer, "too low)")) => (answer, Some(Hint::TooLow)),
+                    _ => (rest.trim(), None),
+                };
+                Some((part.trim().parse().ok()?, answer.to_string(), hint))
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, hint: Option<Hint>) {
+        self.entries.push((part, answer.to_string(), hint));
+    }
+
+    /// Checks `answer` against the wrong answers of `part`, including the bounds implied by
+    /// numeric answers that were too high or too low.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Rejection> {
+        let entries = self.entries.iter().filter(|(p, ..)| *p == part);
+
+        for (_, known, hint) in entries.clone() {
+            if known == answer {
+                return Err(Rejection::Repeated { hint: *hint });
+            }
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        for (_, known, hint) in entries {
+            let (Some(hint), Ok(known_value)) = (hint, known.parse::<i128>()) else {
+                continue;
+            };
+
+            let out_of_bounds = match hint {
+                Hint::TooHigh => value >= known_value,
+                Hint::TooLow => value <= known_value,
+            };
+
+            if out_of_bounds {
+                return Err(Rejection::OutOfBounds {
+                    known: known.clone(),
+                    hint: *hint,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.entries
+            .iter()
+            .try_for_each(|(part, answer, hint)| match hint {
+                Some(hint) => writeln!(f, "{part}: {answer} ({hint})"),
+                None => writeln!(f, "{part}: {answer}"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{mock, Client};
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Response::parse(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::parse(&page("That's not the right answer; your answer is too high.  Please wait one minute before trying again. <a href=\"/2020/day/1\">[Return to Day 1]</a>")),
+            Response::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        assert_eq!(
+            Response::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait.")),
+            Response::RateLimited {
+                wait: Some(Duration::from_secs(94)),
+            }
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Response::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_ledger() {
+        let mut ledger = Ledger::parse("1: 1234 (too high)\n1: abc\n");
+        ledger.record(1, "1000", Some(Hint::TooLow));
+        ledger.record(2, "7", None);
+
+        assert_eq!(
+            ledger.check(1, "abc"),
+            Err(Rejection::Repeated { hint: None })
+        );
+        assert_eq!(
+            ledger.check(1, "1500"),
+            Err(Rejection::OutOfBounds {
+                known: "1234".into(),
+                hint: Hint::TooHigh,
+            })
+        );
+        assert_eq!(
+            ledger.check(1, "999"),
+            Err(Rejection::OutOfBounds {
+                known: "1000".into(),
+                hint: Hint::TooLow,
+            })
+        );
+        assert_eq!(ledger.check(1, "1100"), Ok(()));
+        assert_eq!(ledger.check(2, "1500"), Ok(()));
+        assert_eq!(Ledger::parse(&ledger.to_string()), ledger);
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = mock::serve(vec![(200, &page("That's the right answer!"))]);
+        let client = Client::new(&server.base_url, "secret");
+
+        let html = client.submit(2020, 1, 2, "241861950").unwrap();
+        assert_eq!(Response::parse(&html), Response::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2020/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=241861950");
+    }
+}
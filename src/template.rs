@@ -0,0 +1,157 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Module templates for `cargo scaffold`, stored in `templates/<name>.rs.tmpl`.
+//!
+//! Templates use `{{name}}` placeholders, see [`render`].
+use std::{env, fmt::Display, fs, io, path::PathBuf};
+
+pub const DEFAULT_NAME: &str = "default";
+
+/// The default template, used if `templates/default.rs.tmpl` was removed.
+const DEFAULT: &str = include_str!("../templates/default.rs.tmpl");
+
+const EXTENSION: &str = ".rs.tmpl";
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    UnknownPlaceholder(String),
+    Unclosed,
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateError::NotFound { name, available } => write!(
+                f,
+                "there is no template \"{name}\", available are: {}.",
+                available.join(", ")
+            ),
+            TemplateError::Unreadable { path, source } => {
+                write!(f, "could not read \"{}\": {source}", path.display())
+            }
+            TemplateError::UnknownPlaceholder(name) => {
+                write!(f, "unknown placeholder `{{{{{name}}}}}`.")
+            }
+            TemplateError::Unclosed => write!(f, "a placeholder is missing its closing `}}}}`."),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+pub fn dir() -> PathBuf {
+    env::current_dir().unwrap().join("templates")
+}
+
+/// The names of all templates in [`dir`], sorted.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name();
+            Some(file_name.to_str()?.strip_suffix(EXTENSION)?.to_string())
+        })
+        .collect();
+
+    if !names.iter().any(|name| name == DEFAULT_NAME) {
+        names.push(DEFAULT_NAME.into());
+    }
+
+    names.sort();
+    names
+}
+
+/// Reads the template `name` from [`dir`].
+pub fn load(name: &str) -> Result<String, TemplateError> {
+    let path = dir().join(format!("{name}{EXTENSION}"));
+
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => match name {
+            DEFAULT_NAME => Ok(DEFAULT.to_string()),
+            _ => Err(TemplateError::NotFound {
+                name: name.to_string(),
+                available: available(),
+            }),
+        },
+        Err(source) => Err(TemplateError::Unreadable { path, source }),
+    }
+}
+
+/// Replaces every `{{name}}` in `template` with the value of `name` in `values`. Placeholders
+/// without a value are an error, so that typos do not end up in generated code.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+
+        let end = rest[start..].find("}}").ok_or(TemplateError::Unclosed)? + start;
+        let name = rest[start + 2..end].trim();
+
+        let (_, value) = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .ok_or_else(|| TemplateError::UnknownPlaceholder(name.to_string()))?;
+
+        out.push_str(value);
+        rest = &rest[end + 2..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = [("day", "7"), ("day_padded", "07")];
+
+        assert_eq!(
+            render("pub struct Day{{day_padded}}; // DAY {{ day }}", &values).unwrap(),
+            "pub struct Day07; // DAY 7"
+        );
+        assert!(matches!(
+            render("{{year}}", &values),
+            Err(TemplateError::UnknownPlaceholder(name)) if name == "year"
+        ));
+        assert!(matches!(
+            render("{{day", &values),
+            Err(TemplateError::Unclosed)
+        ));
+    }
+
+    #[test]
+    fn test_bundled_templates() {
+        let values = [
+            ("day", "7"),
+            ("day_padded", "07"),
+            ("year", "2020"),
+            ("title", "Handy Haversacks"),
+            ("part_one_expected", "Some(4)"),
+            ("part_two_expected", "None"),
+        ];
+
+        for name in ["default", "grid", "parse-lines"] {
+            let module = render(&load(name).unwrap(), &values).unwrap();
+            assert!(module.contains("impl Solution for Day07 {"), "{name}");
+            assert!(module.contains("const TITLE: &'static str = \"Handy Haversacks\";"));
+        }
+    }
+}
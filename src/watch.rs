@@ -0,0 +1,360 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Watch mode for `cargo watch-day`: detects changes to the files of a day, rebuilds, and re-runs
+//! its tests and parts in a child `cargo` process.
+use crate::{answers::Answers, examples, file_path};
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::{Command, Output},
+    time::{Duration, Instant, SystemTime},
+};
+
+/// The files of a day whose changes trigger a new run, with their modification times.
+#[derive(Debug, Default, PartialEq)]
+pub struct Snapshot {
+    files: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+impl Snapshot {
+    /// The module of the day, its input, its recorded answers and all of its examples. The
+    /// example directory is listed every time, so that new examples are picked up as well.
+    pub fn take(year: u16, day: u8) -> Self {
+        let mut paths = vec![
+            module_path(year, day),
+            file_path("inputs", year, day),
+            Answers::path(year, day),
+        ];
+
+        let prefix = format!("{day:02}");
+        if let Ok(entries) = fs::read_dir(examples::dir(year)) {
+            let mut example_paths: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix))
+                })
+                .collect();
+            example_paths.sort();
+            paths.extend(example_paths);
+        }
+
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+                (path, modified)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter().map(|(path, _)| path)
+    }
+
+    /// The files that were created or modified since `previous`.
+    pub fn changes(&self, previous: &Snapshot) -> Vec<&PathBuf> {
+        self.files
+            .iter()
+            .filter(|file| !previous.files.contains(file))
+            .map(|(path, _)| path)
+            .collect()
+    }
+}
+
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("src")
+        .join("days")
+        .join(format!("y{year}"))
+        .join(format!("day{day:02}.rs"))
+}
+
+/// The summary lines of `cargo test`, and the names of the failed tests.
+#[derive(Debug, Default, PartialEq)]
+pub struct TestSummary {
+    pub passed: u32,
+    pub failed: u32,
+    pub failures: Vec<String>,
+}
+
+impl TestSummary {
+    /// Parses the output of `cargo test`, e.g. `test result: FAILED. 1 passed; 1 failed; ...`, and
+    /// the headers of the failures: `---- <name> stdout ----` for unit tests and `---- <name> ----`
+    /// for the cases of the examples harness.
+    pub fn parse(output: &str) -> Option<Self> {
+        let mut summary = None;
+        let mut failures = vec![];
+
+        for line in output.lines() {
+            if let Some(name) = line.strip_prefix("---- ").and_then(|rest| {
+                rest.strip_suffix(" stdout ----")
+                    .or_else(|| rest.strip_suffix(" ----"))
+            }) {
+                failures.push(name.to_string());
+            } else if let Some((_, counts)) = line.split_once("test result: ") {
+                let count = |label: &str| {
+                    counts
+                        .split(';')
+                        .find_map(|count| count.trim().strip_suffix(label))
+                        .and_then(|number| number.trim_end().rsplit(' ').next())
+                        .and_then(|number| number.parse::<u32>().ok())
+                        .unwrap_or(0)
+                };
+                let (passed, failed) = summary.unwrap_or((0, 0));
+                summary = Some((passed + count("passed"), failed + count("failed")));
+            }
+        }
+
+        summary.map(|(passed, failed)| Self {
+            passed,
+            failed,
+            failures,
+        })
+    }
+
+    /// Adds up the results of two test runs, `None` if either of them did not report any.
+    pub fn merge(self, other: Option<Self>) -> Option<Self> {
+        let other = other?;
+        let mut failures = self.failures;
+        failures.extend(other.failures);
+
+        Some(Self {
+            passed: self.passed + other.passed,
+            failed: self.failed + other.failed,
+            failures,
+        })
+    }
+}
+
+/// A part as reported by `cargo solve --json`.
+#[derive(Debug, Default, PartialEq)]
+pub struct PartRun {
+    pub part: u8,
+    pub status: String,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+    /// `correct`, `incorrect` or `unknown`, if the part was solved.
+    pub verdict: Option<String>,
+    pub expected: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PartRun {
+    /// Parses a line of [`crate::runner::DayReport::json_lines`].
+    pub fn parse(line: &str) -> Option<Self> {
+        Some(Self {
+            part: json_raw(line, "part")?.parse().ok()?,
+            status: json_string(line, "status")?,
+            answer: json_string(line, "answer"),
+            elapsed: json_raw(line, "nanos")
+                .and_then(|nanos| nanos.parse().ok())
+                .map(Duration::from_nanos),
+            verdict: json_string(line, "verdict"),
+            expected: json_string(line, "expected"),
+            message: json_string(line, "message"),
+        })
+    }
+}
+
+/// The raw value of `key` in a flat JSON object, up to the next `,` or `}`. Only meant for
+/// numbers and `null`.
+fn json_raw<'a>(entry: &'a str, key: &str) -> Option<&'a str> {
+    let (_, value) = entry.split_once(&format!("\"{key}\":"))?;
+    let end = value.find([',', '}']).unwrap_or(value.len());
+    Some(value[..end].trim())
+}
+
+/// The string value of `key` in a flat JSON object, `None` if it is missing or `null`.
+fn json_string(entry: &str, key: &str) -> Option<String> {
+    let (_, value) = entry.split_once(&format!("\"{key}\":"))?;
+    let mut chars = value.trim_start().strip_prefix('"')?.chars();
+    let mut decoded = String::new();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => return Some(decoded),
+            '\\' => match chars.next()? {
+                'n' => decoded.push('\n'),
+                'r' => decoded.push('\r'),
+                't' => decoded.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    decoded.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                escaped => decoded.push(escaped),
+            },
+            char => decoded.push(char),
+        }
+    }
+
+    None
+}
+
+/// The result of rebuilding and re-running a day.
+pub enum Run {
+    /// The crate did not compile, with the output of the compiler.
+    BuildFailed(String),
+    Finished {
+        /// `None` if the output of `cargo test` could not be read.
+        tests: Option<TestSummary>,
+        parts: Vec<PartRun>,
+        /// The output of `cargo solve` if it did not report any parts, e.g. on a crash.
+        error: Option<String>,
+        elapsed: Duration,
+    },
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command.arg("--color=always");
+    command
+}
+
+fn combined(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned() + &String::from_utf8_lossy(&output.stderr)
+}
+
+/// Builds the crate, then runs the unit tests and examples of the day and `cargo solve` for both
+/// parts.
+pub fn run(year: u16, day: u8, timeout: Duration) -> std::io::Result<Run> {
+    let start = Instant::now();
+
+    let build = cargo()
+        .args(["build", "--release", "--quiet", "--bin", "solve"])
+        .output()?;
+    if !build.status.success() {
+        return Ok(Run::BuildFailed(combined(&build)));
+    }
+
+    let filter = format!("days::y{year}::day{day:02}::");
+    let tests = cargo()
+        .args(["test", "--release", "--quiet", "--lib", &filter])
+        .output()?;
+
+    // the cases of `tests/examples.rs` are named like `y2020::day01::example::part_one`.
+    let filter = format!("y{year}::day{day:02}::");
+    let example_tests = cargo()
+        .args([
+            "test",
+            "--release",
+            "--quiet",
+            "--test",
+            "examples",
+            "--",
+            &filter,
+        ])
+        .output()?;
+
+    let tests = TestSummary::parse(&String::from_utf8_lossy(&tests.stdout)).and_then(|tests| {
+        tests.merge(TestSummary::parse(&String::from_utf8_lossy(
+            &example_tests.stdout,
+        )))
+    });
+
+    let solve = cargo()
+        .args(["run", "--release", "--quiet", "--bin", "solve", "--"])
+        .args([&day.to_string(), "--year", &year.to_string(), "--json"])
+        .args(["--timeout-ms", &timeout.as_millis().to_string()])
+        .output()?;
+    let parts: Vec<PartRun> = String::from_utf8_lossy(&solve.stdout)
+        .lines()
+        .filter(|line| line.starts_with("{\"year\""))
+        .filter_map(PartRun::parse)
+        .collect();
+    let error = parts.is_empty().then(|| combined(&solve));
+
+    Ok(Run::Finished {
+        tests,
+        parts,
+        error,
+        elapsed: start.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_part_run() {
+        let line = r#"{"year":2020,"day":1,"part":2,"status":"solved","answer":"a\"b","nanos":52061,"stats":null,"peak_bytes":null,"allocations":null,"verdict":"incorrect","expected":"7","message":null}"#;
+
+        assert_eq!(
+            PartRun::parse(line),
+            Some(PartRun {
+                part: 2,
+                status: "solved".into(),
+                answer: Some("a\"b".into()),
+                elapsed: Some(Duration::from_nanos(52061)),
+                verdict: Some("incorrect".into()),
+                expected: Some("7".into()),
+                message: None,
+            })
+        );
+        assert_eq!(PartRun::parse("{\"year\":2020}"), None);
+    }
+
+    #[test]
+    fn test_parse_test_summary() {
+        let output = "
+running 2 tests
+.F
+failures:
+
+---- days::y2020::day01::tests::test_part_two stdout ----
+assertion `left == right` failed
+
+failures:
+    days::y2020::day01::tests::test_part_two
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 60 filtered out; finished in 0.00s
+";
+
+        assert_eq!(
+            TestSummary::parse(output),
+            Some(TestSummary {
+                passed: 1,
+                failed: 1,
+                failures: vec!["days::y2020::day01::tests::test_part_two".into()],
+            })
+        );
+        assert_eq!(TestSummary::parse("error: could not compile"), None);
+
+        let examples = "
+running 2 tests
+test y2020::day01::example::part_one ... ok
+test y2020::day01::example::part_two ... FAILED
+
+failures:
+
+---- y2020::day01::example::part_two ----
+example: src/examples/2020/01.txt
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 59 filtered out
+";
+
+        assert_eq!(
+            TestSummary::parse(output)
+                .unwrap()
+                .merge(TestSummary::parse(examples)),
+            Some(TestSummary {
+                passed: 2,
+                failed: 2,
+                failures: vec![
+                    "days::y2020::day01::tests::test_part_two".into(),
+                    "y2020::day01::example::part_two".into(),
+                ],
+            })
+        );
+        assert_eq!(TestSummary::default().merge(None), None);
+    }
+}
@@ -0,0 +1,222 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+//! Runs every example in `src/examples` through the registered solution of its day.
+//!
+//! One test case is generated per day, example and part with a declared answer, named like
+//! `y2020::day10::example::part_one` or `y2020::day10::example::larger::part_two`. Filters and the common
+//! libtest flags (`--exact`, `--skip`, `--list`, `--ignored`) work as with regular tests,
+//! e.g. `cargo test day10`.
+use advent_of_code::{
+    days, examples,
+    runner::{self, Options, Outcome},
+    Solver,
+};
+use std::{
+    fs,
+    path::PathBuf,
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+struct Case {
+    name: String,
+    path: PathBuf,
+    solver: Solver,
+    expected: String,
+}
+
+enum Failure {
+    /// The part did not produce the expected answer. `actual` is the answer or what happened instead.
+    Mismatch {
+        actual: String,
+    },
+    Io(String),
+}
+
+struct Args {
+    filters: Vec<String>,
+    skip: Vec<String>,
+    exact: bool,
+    list: bool,
+    ignored: bool,
+}
+
+fn parse_args() -> Args {
+    let mut args = pico_args::Arguments::from_env();
+
+    let parsed = Args {
+        skip: args.values_from_str("--skip").unwrap_or_default(),
+        exact: args.contains("--exact"),
+        list: args.contains("--list"),
+        ignored: args.contains("--ignored"),
+        filters: vec![],
+    };
+
+    // flags of the default harness that take a value and have no meaning here.
+    for flag in ["--test-threads", "--format", "--color", "--logfile", "-Z"] {
+        let _: Option<String> = args.opt_value_from_str(flag).unwrap_or_default();
+    }
+
+    let filters = args
+        .finish()
+        .into_iter()
+        .filter_map(|arg| arg.into_string().ok())
+        .filter(|arg| !arg.starts_with('-'))
+        .collect();
+
+    Args { filters, ..parsed }
+}
+
+impl Args {
+    fn matches(&self, name: &str) -> bool {
+        let matches = |pattern: &String| {
+            if self.exact {
+                name == pattern
+            } else {
+                name.contains(pattern.as_str())
+            }
+        };
+
+        (self.filters.is_empty() || self.filters.iter().any(matches))
+            && !self.skip.iter().any(matches)
+    }
+}
+
+fn collect_cases() -> Vec<Case> {
+    let mut cases = vec![];
+
+    for day in days::all() {
+        let examples = examples::find(day.year, day.day).unwrap_or_else(|e| {
+            eprintln!(
+                "could not read examples of day {:02} of {}: {e}",
+                day.day, day.year
+            );
+            process::exit(1);
+        });
+
+        for example in examples {
+            for (part, solver) in day.parts() {
+                let Some(expected) = example.answers.get(part) else {
+                    continue;
+                };
+
+                let name = match &example.name {
+                    Some(name) => format!("y{}::day{:02}::example::{name}", day.year, day.day),
+                    None => format!("y{}::day{:02}::example", day.year, day.day),
+                };
+
+                cases.push(Case {
+                    name: format!("{name}::part_{}", part_name(part)),
+                    expected: expected.to_string(),
+                    path: example.path.clone(),
+                    solver,
+                });
+            }
+        }
+    }
+
+    cases
+}
+
+fn part_name(part: u8) -> &'static str {
+    match part {
+        1 => "one",
+        _ => "two",
+    }
+}
+
+fn run_case(case: &Case) -> Result<(), Failure> {
+    let input = fs::read_to_string(&case.path)
+        .map_err(|e| Failure::Io(format!("{}: {e}", case.path.display())))?;
+
+    match runner::run_part(case.solver, &input, &Options::default()) {
+        Outcome::Solved { answer, .. } if answer == case.expected => Ok(()),
+        Outcome::Solved { answer, .. } => Err(Failure::Mismatch { actual: answer }),
+        Outcome::Unsolved => Err(Failure::Mismatch {
+            actual: "no answer".into(),
+        }),
+        outcome => Err(Failure::Mismatch {
+            actual: outcome.to_string(),
+        }),
+    }
+}
+
+fn describe(case: &Case, failure: &Failure) -> String {
+    let details = match failure {
+        Failure::Mismatch { actual } => format!(
+            "\x1b[32m- expected: {}\x1b[0m\n\x1b[31m+ actual:   {actual}\x1b[0m",
+            case.expected
+        ),
+        Failure::Io(message) => format!("could not read example: {message}"),
+    };
+
+    format!(
+        "---- {} ----\nexample: {}\n{details}",
+        case.name,
+        case.path.display()
+    )
+}
+
+fn main() {
+    let args = parse_args();
+
+    let (cases, filtered): (Vec<Case>, Vec<Case>) = collect_cases()
+        .into_iter()
+        .partition(|case| !args.ignored && args.matches(&case.name));
+
+    if args.list {
+        for case in &cases {
+            println!("{}: test", case.name);
+        }
+        return;
+    }
+
+    println!("\nrunning {} tests", cases.len());
+
+    let next = AtomicUsize::new(0);
+    let failures = Mutex::new(vec![]);
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(cases.len()) {
+            scope.spawn(|| {
+                while let Some(case) = cases.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_case(case);
+                    let status = if result.is_ok() { "ok" } else { "FAILED" };
+                    println!("test {} ... {status}", case.name);
+
+                    if let Err(failure) = result {
+                        failures.lock().unwrap().push(describe(case, &failure));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut failures = failures.into_inner().unwrap();
+    failures.sort();
+
+    if !failures.is_empty() {
+        println!("\nfailures:\n\n{}\n", failures.join("\n\n"));
+    }
+
+    let result = if failures.is_empty() { "ok" } else { "FAILED" };
+
+    println!(
+        "\ntest result: {result}. {} passed; {} failed; 0 ignored; 0 measured; {} filtered out\n",
+        cases.len() - failures.len(),
+        failures.len(),
+        filtered.len()
+    );
+
+    if !failures.is_empty() {
+        process::exit(101);
+    }
+}